@@ -0,0 +1,257 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CONFIG_FILE: &str = "unnie_mod_manager_config.json";
+/// Environment variable overriding the folder the config file is kept in
+pub const CONFIG_DIR_VAR: &str = "UNNIE_CONFIG_DIR";
+/// Environment variable overriding the folder the GUI cache file is kept in
+pub const CACHE_DIR_VAR: &str = "UNNIE_CACHE_DIR";
+/// Environment variable overriding the folder automatic backups are written to
+pub const BACKUP_DIR_VAR: &str = "UNNIE_BACKUP_DIR";
+/// Environment variable overriding the folder the log files are kept in
+pub const LOG_DIR_VAR: &str = "UNNIE_LOG_DIR";
+
+/// Color theme used by the GUI
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    /// Follow the operating system's light/dark preference
+    System,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::System];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Theme::Dark => "Dark",
+            Theme::Light => "Light",
+            Theme::System => "System",
+        }
+    }
+}
+
+/// Where to install mod archives whose type can't be detected from their contents
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum UnknownModTarget {
+    #[default]
+    Mods,
+    LogicMods,
+    /// Ask each time in the GUI; the CLI falls back to Mods
+    Ask,
+}
+
+impl UnknownModTarget {
+    pub const ALL: [UnknownModTarget; 3] = [UnknownModTarget::Mods, UnknownModTarget::LogicMods, UnknownModTarget::Ask];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            UnknownModTarget::Mods => "Mods",
+            UnknownModTarget::LogicMods => "LogicMods",
+            UnknownModTarget::Ask => "Ask",
+        }
+    }
+
+    /// The target to use without asking
+    pub fn install_target(self) -> crate::core::InstallTarget {
+        match self {
+            UnknownModTarget::LogicMods => crate::core::InstallTarget::LogicMods,
+            UnknownModTarget::Mods | UnknownModTarget::Ask => crate::core::InstallTarget::Mods,
+        }
+    }
+}
+
+/// A named game install, such as a test copy of the game kept next to the main one
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
+pub struct GameTarget {
+    pub name: String,
+    pub win64_dir: String,
+}
+
+/// User settings, kept separate from the cache so they survive a cache reset
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub theme: Theme,
+    /// Black-and-white theme with thick outlines and larger buttons, replacing `theme`
+    pub high_contrast: bool,
+    /// GUI language code, matching one of the embedded locales (e.g. "en", "fr")
+    pub language: String,
+    /// Folder under Win64 that mods are installed into; some setups use `LuaMods` or similar
+    pub mods_folder_name: String,
+    /// Proxy for downloads (e.g. `http://proxy.example:8080`); when unset the
+    /// `HTTP_PROXY`/`HTTPS_PROXY` environment variables are used
+    pub proxy_url: Option<String>,
+    /// Seconds to wait for a connection, or for more data during a download, before giving up
+    pub download_timeout_secs: u64,
+    /// Keep downloaded archives in a `cache` folder and reuse them on later installs
+    pub cache_downloads: bool,
+    /// How many mods Update All downloads at once
+    pub max_concurrent_downloads: usize,
+    /// Where to fetch updates to the list of known problem mods; when unset only the built-in list is used
+    pub compat_db_url: Option<String>,
+    /// Catalog of mods shown in Browse Mods; browsing is unavailable when unset
+    pub mod_index_url: Option<String>,
+    /// Font file used for characters egui's built-in fonts lack, such as CJK mod names;
+    /// when unset a system font is looked for
+    pub font_path: Option<String>,
+    /// If non-empty, only archive entries matching one of these globs are installed
+    pub include_patterns: Vec<String>,
+    /// Archive entries matching any of these globs are skipped during install
+    pub exclude_patterns: Vec<String>,
+    /// Where archives that can't be classified are installed
+    pub default_unknown_target: UnknownModTarget,
+    /// Add installed mods to `mods.txt` as enabled, so UE4SS loads them without further steps
+    pub auto_enable_on_install: bool,
+    /// Strip extra folders wrapped around a mod in its archive, e.g. `ModName/ModName/Scripts`
+    pub flatten_wrapper_folders: bool,
+    /// Steam app id of the game, for regional variants or if it ever changes
+    pub steam_app_id: u32,
+    /// Order of the installed mods list in the GUI
+    pub mod_sort: crate::core::ModSort,
+    /// Re-read the installed mods list every few seconds, for folders the watcher can't see into
+    pub auto_refresh: bool,
+    /// Named game installs that can be switched between in the GUI or passed by name as `--target-dir`
+    pub targets: Vec<GameTarget>,
+    /// Shell command run after each successful mod install, with the installed mod's folder
+    /// appended as its last argument. Runs arbitrary commands, so it's only set by hand.
+    pub post_install_command: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            high_contrast: false,
+            language: "en".to_string(),
+            mods_folder_name: crate::core::DEFAULT_MODS_FOLDER_NAME.to_string(),
+            proxy_url: None,
+            download_timeout_secs: crate::core::DEFAULT_DOWNLOAD_TIMEOUT.as_secs(),
+            cache_downloads: false,
+            max_concurrent_downloads: crate::core::DEFAULT_MAX_CONCURRENT_DOWNLOADS,
+            compat_db_url: None,
+            mod_index_url: None,
+            font_path: None,
+            include_patterns: Vec::new(),
+            exclude_patterns: crate::core::DEFAULT_EXCLUDE_PATTERNS.iter().map(|p| p.to_string()).collect(),
+            default_unknown_target: UnknownModTarget::default(),
+            auto_enable_on_install: true,
+            flatten_wrapper_folders: false,
+            steam_app_id: crate::core::DEFAULT_GAME_APP_ID,
+            mod_sort: crate::core::ModSort::default(),
+            auto_refresh: false,
+            targets: Vec::new(),
+            post_install_command: None,
+        }
+    }
+}
+
+impl Config {
+    /// The Win64 path of the target named `name_or_path`, or `name_or_path` itself if no target has that name
+    pub fn resolve_target<'a>(&'a self, name_or_path: &'a str) -> &'a str {
+        self.targets
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name_or_path.trim()))
+            .map(|t| t.win64_dir.as_str())
+            .unwrap_or(name_or_path)
+    }
+
+    /// The target pointing at `win64_dir`, if any
+    pub fn target_for_dir(&self, win64_dir: &str) -> Option<&GameTarget> {
+        let dir = Path::new(win64_dir.trim());
+        self.targets.iter().find(|t| Path::new(t.win64_dir.trim()) == dir)
+    }
+
+    /// Add a target, or repoint the existing one with the same name
+    pub fn set_target(&mut self, name: &str, win64_dir: &str) {
+        let name = name.trim();
+        match self.targets.iter_mut().find(|t| t.name.eq_ignore_ascii_case(name)) {
+            Some(target) => target.win64_dir = win64_dir.to_string(),
+            None => self.targets.push(GameTarget { name: name.to_string(), win64_dir: win64_dir.to_string() }),
+        }
+    }
+
+    pub fn network_settings(&self) -> crate::core::NetworkSettings {
+        crate::core::NetworkSettings {
+            proxy_url: self.proxy_url.clone().filter(|url| !url.trim().is_empty()),
+            timeout: std::time::Duration::from_secs(self.download_timeout_secs.max(1)),
+            cache_downloads: self.cache_downloads,
+            max_concurrent_downloads: self.max_concurrent_downloads.max(1),
+        }
+    }
+
+
+    pub fn entry_filter(&self) -> Result<crate::core::EntryFilter, String> {
+        crate::core::EntryFilter::new(&self.include_patterns, &self.exclude_patterns)
+    }
+}
+
+/// The folder a data file is kept in: the directory named by `env_var` when it's set, otherwise
+/// the platform's data folder for the manager (e.g. `%APPDATA%\UnnieModManager\data`), so the
+/// files are found no matter which working directory the manager was started from
+pub fn resolve_data_dir(env_var: &str) -> PathBuf {
+    if let Some(dir) = data_dir_override(env_var) {
+        return dir;
+    }
+    directories::ProjectDirs::from("", "", "UnnieModManager")
+        .map(|dirs| dirs.data_dir().to_path_buf())
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// The directory named by `env_var`, if it's set and not empty
+pub fn data_dir_override(env_var: &str) -> Option<PathBuf> {
+    std::env::var_os(env_var).filter(|dir| !dir.is_empty()).map(PathBuf::from)
+}
+
+/// Move `file_name` out of the working directory, where older versions kept it, to `path` when
+/// nothing is there yet. Returns where to read the file from: `path`, or the old location if
+/// the move failed.
+pub fn migrate_legacy_file(path: PathBuf, file_name: &str) -> PathBuf {
+    let legacy = Path::new(file_name);
+    if path.exists() || !legacy.is_file() {
+        return path;
+    }
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    // Renaming fails when the data folder is on another drive, so fall back to copying
+    let moved = fs::rename(legacy, &path).or_else(|_| fs::copy(legacy, &path).and_then(|_| fs::remove_file(legacy)));
+    match moved {
+        Ok(()) => {
+            log::info!("Moved {} to {}", file_name, path.display());
+            path
+        }
+        Err(e) => {
+            log::error!("Failed to move {} to {}: {}", file_name, path.display(), e);
+            PathBuf::from(file_name)
+        }
+    }
+}
+
+/// Where the config file is saved; see [`resolve_data_dir`]
+pub fn config_path() -> PathBuf {
+    resolve_data_dir(CONFIG_DIR_VAR).join(CONFIG_FILE)
+}
+
+pub fn load_config() -> Config {
+    let path = migrate_legacy_file(config_path(), CONFIG_FILE);
+    if path.exists() {
+        let data = fs::read_to_string(&path).unwrap_or_default();
+        serde_json::from_str(&data).unwrap_or_default()
+    } else {
+        Config::default()
+    }
+}
+
+pub fn save_config(config: &Config) {
+    let path = config_path();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+    if let Ok(data) = serde_json::to_string_pretty(config) {
+        let _ = fs::write(path, data);
+    }
+}
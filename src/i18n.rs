@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// Embedded locales as (code, display name, JSON table). English is the fallback
+/// for any key a translation is missing.
+const LOCALES: &[(&str, &str, &str)] = &[
+    ("en", "English", include_str!("../locales/en.json")),
+    ("fr", "Français", include_str!("../locales/fr.json")),
+];
+
+const FALLBACK_LANGUAGE: &str = "en";
+
+struct Translations {
+    current: HashMap<String, String>,
+    fallback: HashMap<String, String>,
+}
+
+fn parse_locale(code: &str) -> HashMap<String, String> {
+    LOCALES
+        .iter()
+        .find(|(locale, _, _)| *locale == code)
+        .and_then(|(_, _, json)| serde_json::from_str(json).ok())
+        .unwrap_or_default()
+}
+
+fn translations() -> &'static RwLock<Translations> {
+    static TRANSLATIONS: OnceLock<RwLock<Translations>> = OnceLock::new();
+    TRANSLATIONS.get_or_init(|| {
+        RwLock::new(Translations {
+            current: parse_locale(FALLBACK_LANGUAGE),
+            fallback: parse_locale(FALLBACK_LANGUAGE),
+        })
+    })
+}
+
+/// Available languages as (code, display name)
+pub fn languages() -> impl Iterator<Item = (&'static str, &'static str)> {
+    LOCALES.iter().map(|(code, name, _)| (*code, *name))
+}
+
+pub fn language_name(code: &str) -> &'static str {
+    languages()
+        .find(|(locale, _)| *locale == code)
+        .map(|(_, name)| name)
+        .unwrap_or("English")
+}
+
+/// Switch the active language; unknown codes fall back to English
+pub fn set_language(code: &str) {
+    if let Ok(mut table) = translations().write() {
+        table.current = parse_locale(code);
+    }
+}
+
+/// Look up a GUI string by key in the active language, falling back to English
+/// and then to the key itself so a missing entry is visible rather than blank.
+pub fn t(key: &str) -> String {
+    match translations().read() {
+        Ok(table) => table
+            .current
+            .get(key)
+            .or_else(|| table.fallback.get(key))
+            .cloned()
+            .unwrap_or_else(|| key.to_string()),
+        Err(_) => key.to_string(),
+    }
+}
@@ -0,0 +1,70 @@
+use crate::config;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// The log is rotated at startup once it grows past this
+const MAX_LOG_SIZE: u64 = 5 * 1024 * 1024;
+/// Rotated logs kept as `unnie.log.1` (newest) to `unnie.log.3`
+const KEPT_LOGS: usize = 3;
+
+/// `logs/unnie.log` in the manager's data folder; see [`config::resolve_data_dir`]
+pub fn log_path() -> PathBuf {
+    config::resolve_data_dir(config::LOG_DIR_VAR).join("logs").join("unnie.log")
+}
+
+/// Send this crate's log records to stderr, formatted as before (`[DEBUG] ...`), and to the
+/// log file with timestamps, whatever the GUI's debug mode. Other crates only log warnings.
+/// If the log file can't be opened, logging goes to stderr alone.
+pub fn init() {
+    let stderr = fern::Dispatch::new()
+        .format(|out, message, record| match record.level() {
+            log::Level::Info => out.finish(format_args!("{}", message)),
+            level => out.finish(format_args!("[{}] {}", level, message)),
+        })
+        .chain(std::io::stderr());
+    let mut dispatch = fern::Dispatch::new()
+        .level(log::LevelFilter::Warn)
+        .level_for(env!("CARGO_CRATE_NAME"), log::LevelFilter::Debug)
+        .chain(stderr);
+    match open_log_file() {
+        Ok(file) => {
+            let file = fern::Dispatch::new()
+                .format(|out, message, record| {
+                    out.finish(format_args!(
+                        "{} [{}] {}",
+                        humantime::format_rfc3339_seconds(SystemTime::now()),
+                        record.level(),
+                        message
+                    ))
+                })
+                .chain(file);
+            dispatch = dispatch.chain(file);
+        }
+        Err(e) => eprintln!("[ERROR] Could not open log file {}: {}", log_path().display(), e),
+    }
+    if let Err(e) = dispatch.apply() {
+        eprintln!("[ERROR] Could not set up logging: {}", e);
+    }
+}
+
+fn open_log_file() -> std::io::Result<fs::File> {
+    let path = log_path();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let too_big = fs::metadata(&path).map(|metadata| metadata.len() > MAX_LOG_SIZE).unwrap_or(false);
+    if too_big {
+        rotate(&path);
+    }
+    fern::log_file(&path)
+}
+
+/// Shift `unnie.log.N` to `.N+1`, dropping the oldest, and move the current log to `.1`
+fn rotate(path: &Path) {
+    let numbered = |n: usize| PathBuf::from(format!("{}.{}", path.display(), n));
+    for n in (1..KEPT_LOGS).rev() {
+        let _ = fs::rename(numbered(n), numbered(n + 1));
+    }
+    let _ = fs::rename(path, numbered(1));
+}
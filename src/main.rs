@@ -1,18 +1,38 @@
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+mod config;
 mod core;
+mod i18n;
+mod logging;
 
+use config::{Config, Theme, UnknownModTarget};
+use i18n::t;
 use eframe::egui;
 use rfd::FileDialog;
 use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
 const CACHE_FILE: &str = "unnie_mod_manager_cache.json";
+/// How many directories the "recent" dropdown remembers
+const MAX_RECENT_DIRS: usize = 8;
+/// How often to check whether the game has been started or closed
+const GAME_CHECK_INTERVAL: Duration = Duration::from_secs(3);
+/// How often the installed mods list is re-read when auto-refresh is on
+const AUTO_REFRESH_INTERVAL: Duration = Duration::from_secs(5);
 
 #[derive(Parser)]
 #[command(name = "UnnieModManager")]
 #[command(about = "A CLI tool to manage UE4SS and mods for Expedition 33", long_about = None)]
 struct Cli {
+    /// Print results as JSON on stdout (logs go to stderr)
+    #[arg(long, global = true)]
+    json: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -21,51 +41,172 @@ struct Cli {
 enum Commands {
     /// Install or update UE4SS in the target game directory
     InstallUe4ss {
-        /// Path to the game Win64 directory
+        /// Path to the game Win64 directory, or the name of a configured target
         #[arg(short, long)]
         target_dir: String,
+        /// List the files that would be installed without writing anything
+        #[arg(long)]
+        dry_run: bool,
     },
-    /// Install a mod from a zip file (future: drag-and-drop in GUI)
+    /// Install a mod from a zip file or a direct download URL
     InstallMod {
         /// Path to the mod zip file
-        #[arg(short, long)]
-        zip_path: String,
-        /// Path to the game Win64 directory
+        #[arg(short, long, required_unless_present = "url", conflicts_with = "url")]
+        zip_path: Option<String>,
+        /// Direct download URL of the mod zip
+        #[arg(long)]
+        url: Option<String>,
+        /// Expected SHA-256 of the downloaded archive (only with --url)
+        #[arg(long, requires = "url")]
+        sha256: Option<String>,
+        /// Only install archive entries matching this glob (repeatable; overrides the config)
+        #[arg(long)]
+        include: Vec<String>,
+        /// Skip archive entries matching this glob (repeatable; overrides the config)
+        #[arg(long)]
+        exclude: Vec<String>,
+        /// Path to the game Win64 directory, or the name of a configured target
         #[arg(short, long)]
         target_dir: String,
+        /// Remove files from the previous install of this mod before extracting
+        #[arg(long)]
+        clean: bool,
+        /// Install even if the archive contains executables, scripts, or DLLs outside `dlls/`
+        #[arg(long)]
+        allow_risky: bool,
     },
     /// List installed mods in the Mods folder
     ListMods {
-        /// Path to the game Win64 directory
+        /// Path to the game Win64 directory, or the name of a configured target
+        #[arg(short, long)]
+        target_dir: String,
+    },
+    /// Back up and remove all installed mods, returning the game to vanilla
+    ResetVanilla {
+        /// Path to the game Win64 directory, or the name of a configured target
+        #[arg(short, long)]
+        target_dir: String,
+        /// Also remove blueprint mod paks from LogicMods
+        #[arg(long)]
+        logic_mods: bool,
+        /// Also remove UE4SS itself
+        #[arg(long)]
+        remove_ue4ss: bool,
+    },
+    /// Re-download every mod that has a source URL and reinstall the ones that changed
+    UpdateAll {
+        /// Path to the game Win64 directory, or the name of a configured target
         #[arg(short, long)]
         target_dir: String,
     },
+    /// Check a mod archive is packaged correctly before sharing it, without installing anything
+    ValidateMod {
+        /// Path to the mod zip or 7z file
+        zip_path: String,
+    },
+    /// Print the effective configuration and the files and folders the manager uses
+    Config {
+        /// Path to the game Win64 directory or a target name, to also show its Mods, LogicMods, and backup folders
+        #[arg(short, long)]
+        target_dir: Option<String>,
+    },
+    /// Print the manager's version and the UE4SS version it installs
+    Version,
     /// Launch the GUI
     Gui,
+    /// Print a shell completion script to stdout, e.g. `completions zsh > _UnnieModManager`
+    #[command(hide = true)]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: clap_complete::Shell,
+    },
 }
 
 #[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
 pub struct AppCache {
     pub last_selected_game: Option<usize>,
     pub last_win64_dir: String,
     pub last_installed_mods: Vec<String>,
     pub last_scanned_files: Vec<String>,
     pub last_debug_output: String,
+    pub window: Option<WindowGeometry>,
+    /// Win64 directories used before, most recent first
+    pub recent_win64_dirs: Vec<String>,
+}
+
+impl AppCache {
+    /// Move `dir` to the front of the recent directories, dropping duplicates and the oldest entries
+    fn remember_dir(&mut self, dir: &str) {
+        let dir = dir.trim();
+        if dir.is_empty() {
+            return;
+        }
+        self.recent_win64_dirs.retain(|recent| !recent.eq_ignore_ascii_case(dir));
+        self.recent_win64_dirs.insert(0, dir.to_string());
+        self.recent_win64_dirs.truncate(MAX_RECENT_DIRS);
+    }
+}
+
+/// Window size and position remembered between GUI sessions
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct WindowGeometry {
+    pub size: [f32; 2],
+    pub pos: Option<[f32; 2]>,
+    /// Size of the monitor the window was on when saved, used to spot display changes
+    pub monitor_size: Option<[f32; 2]>,
+}
+
+/// Where the GUI cache file is saved, resolved like the config file but honoring
+/// [`config::CACHE_DIR_VAR`]
+fn cache_path() -> PathBuf {
+    config::resolve_data_dir(config::CACHE_DIR_VAR).join(CACHE_FILE)
 }
 
 fn load_cache() -> AppCache {
-    if Path::new(CACHE_FILE).exists() {
-        let data = fs::read_to_string(CACHE_FILE).unwrap_or_default();
-        serde_json::from_str(&data).unwrap_or_default()
-    } else {
-        AppCache::default()
+    let path = config::migrate_legacy_file(cache_path(), CACHE_FILE);
+    if !path.exists() {
+        return AppCache::default();
+    }
+    let data = fs::read_to_string(&path).unwrap_or_default();
+    match serde_json::from_str(&data) {
+        Ok(cache) => cache,
+        Err(e) => {
+            // Keep the unreadable file so its contents can still be recovered by hand
+            let mut corrupt = path.clone().into_os_string();
+            corrupt.push(".corrupt");
+            log::error!("Could not read {} ({}); moved it to {} and starting fresh", path.display(), e, Path::new(&corrupt).display());
+            let _ = fs::rename(&path, &corrupt);
+            AppCache::default()
+        }
     }
 }
 
 fn save_cache(cache: &AppCache) {
+    let path = cache_path();
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
     if let Ok(data) = serde_json::to_string_pretty(cache) {
-        let _ = fs::write(CACHE_FILE, data);
+        if let Err(e) = write_atomically(&path, data.as_bytes()) {
+            log::error!("Failed to save {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// Write `data` to a temp file next to `path` and rename it into place, so a crash
+/// mid-write leaves either the old file or the new one, never a truncated mix
+fn write_atomically(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut temp_name = path.as_os_str().to_owned();
+    temp_name.push(".tmp");
+    let temp_path = PathBuf::from(temp_name);
+    {
+        let mut file = fs::File::create(&temp_path)?;
+        std::io::Write::write_all(&mut file, data)?;
+        file.sync_all()?;
     }
+    fs::rename(&temp_path, path)
 }
 
 #[cfg(windows)]
@@ -81,69 +222,1006 @@ fn is_elevated() -> bool {
     true
 }
 
+/// A failed CLI command, carrying the process exit code to report it with
+struct CliError {
+    /// 1 when the operation failed, 2 for invalid arguments or input
+    code: i32,
+    message: String,
+    /// Extra fields merged into the `--json` error object
+    details: Option<serde_json::Value>,
+}
+
+impl CliError {
+    fn failed(message: impl Into<String>) -> Self {
+        Self { code: 1, message: message.into(), details: None }
+    }
+
+    fn usage(message: impl Into<String>) -> Self {
+        Self { code: 2, message: message.into(), details: None }
+    }
+}
+
 fn main() {
     let cli = Cli::parse();
-    match cli.command {
-        Commands::InstallUe4ss { target_dir } => {
-            core::install_ue4ss(&target_dir).expect("Failed to install UE4SS");
+    logging::init();
+    let json = cli.json;
+    let config = config::load_config();
+    if let Err(e) = core::set_mods_folder_name(&config.mods_folder_name) {
+        log::error!("Invalid mods_folder_name in config, using '{}': {}", core::DEFAULT_MODS_FOLDER_NAME, e);
+    }
+    core::set_network_settings(config.network_settings());
+    core::set_unknown_mod_target(config.default_unknown_target.install_target());
+    core::set_auto_enable_on_install(config.auto_enable_on_install);
+    core::set_flatten_wrapper_folders(config.flatten_wrapper_folders);
+    core::set_post_install_command(config.post_install_command.clone());
+    core::set_game_app_id(config.steam_app_id);
+    match config.entry_filter() {
+        Ok(filter) => core::set_entry_filter(filter),
+        Err(e) => log::error!("Invalid file patterns in config, using the defaults: {}", e),
+    }
+    let result = match cli.command {
+        Commands::InstallUe4ss { target_dir, dry_run } => cmd_install_ue4ss(config.resolve_target(&target_dir), dry_run, json),
+        Commands::InstallMod { zip_path, url, sha256, include, exclude, target_dir, clean, allow_risky } => {
+            let target_dir = config.resolve_target(&target_dir).to_string();
+            override_entry_filter(&config, include, exclude).and_then(|()| {
+                cmd_install_mod(zip_path.as_deref(), url.as_deref(), sha256.as_deref(), &target_dir, clean, allow_risky, json)
+            })
         }
-        Commands::InstallMod { zip_path, target_dir } => {
-            match core::install_mod_from_zip(&zip_path, &target_dir) {
-                Ok(_) => println!("Mod installed successfully."),
-                Err(e) => println!("[ERROR] Failed to install mod: {}", e),
-            }
+        Commands::ListMods { target_dir } => cmd_list_mods(config.resolve_target(&target_dir), json),
+        Commands::ResetVanilla { target_dir, logic_mods, remove_ue4ss } => {
+            cmd_reset_vanilla(config.resolve_target(&target_dir), logic_mods, remove_ue4ss, json)
         }
-        Commands::ListMods { target_dir } => {
-            match core::list_installed_mods(&target_dir) {
-                Ok(mods) => {
-                    if mods.is_empty() {
-                        println!("No mods installed.");
-                    } else {
-                        println!("Installed mods:");
-                        for m in mods {
-                            println!("- {}", m);
-                        }
-                    }
-                }
-                Err(e) => println!("Error listing mods: {}", e),
-            }
+        Commands::UpdateAll { target_dir } => cmd_update_all(config.resolve_target(&target_dir), json),
+        Commands::ValidateMod { zip_path } => cmd_validate_mod(&zip_path, json),
+        Commands::Config { target_dir } => cmd_config(&config, target_dir.as_deref().map(|dir| config.resolve_target(dir)), json),
+        Commands::Version => {
+            cmd_version(json);
+            Ok(())
         }
         Commands::Gui => {
             run_gui();
+            Ok(())
+        }
+        Commands::Completions { shell } => {
+            let mut command = Cli::command();
+            let name = command.get_name().to_string();
+            clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        if json {
+            let mut error = serde_json::json!({ "error": e.message });
+            if let (Some(serde_json::Value::Object(details)), Some(object)) = (e.details, error.as_object_mut()) {
+                object.extend(details);
+            }
+            print_json(error);
+        } else {
+            log::error!("{}", e.message);
+        }
+        std::process::exit(e.code);
+    }
+}
+
+/// Check that a `--target-dir` argument points at an existing directory
+fn validate_target_dir(target_dir: &str) -> Result<(), CliError> {
+    if Path::new(target_dir).is_dir() {
+        Ok(())
+    } else {
+        Err(CliError::usage(format!("Target directory does not exist: {}", target_dir)))
+    }
+}
+
+fn cmd_install_ue4ss(target_dir: &str, dry_run: bool, json: bool) -> Result<(), CliError> {
+    validate_target_dir(target_dir)?;
+    if dry_run {
+        let planned = core::preview_ue4ss_install(target_dir, &AtomicU64::new(0), &AtomicBool::new(false))
+            .map_err(|e| CliError::failed(format!("Failed to preview UE4SS install: {}", e)))?;
+        let planned: Vec<String> = planned.iter().map(|path| path.display().to_string()).collect();
+        if json {
+            print_json(serde_json::json!({ "status": "ok", "planned": planned }));
+        } else {
+            println!("UE4SS would install {} files:", planned.len());
+            for path in planned {
+                println!("- {}", path);
+            }
+        }
+        return Ok(());
+    }
+    core::preflight_check(target_dir, 0).map_err(|e| CliError::failed(e.to_string()))?;
+    core::install_ue4ss(target_dir, &AtomicBool::new(false)).map_err(|e| CliError::failed(format!("Failed to install UE4SS: {}", e)))?;
+    if json {
+        print_json(serde_json::json!({ "status": "ok" }));
+    } else {
+        println!("UE4SS installed and verified.");
+    }
+    Ok(())
+}
+
+/// Replace the configured include/exclude patterns with any given on the command line
+fn override_entry_filter(config: &Config, include: Vec<String>, exclude: Vec<String>) -> Result<(), CliError> {
+    if include.is_empty() && exclude.is_empty() {
+        return Ok(());
+    }
+    let include = if include.is_empty() { config.include_patterns.clone() } else { include };
+    let exclude = if exclude.is_empty() { config.exclude_patterns.clone() } else { exclude };
+    let filter = core::EntryFilter::new(&include, &exclude).map_err(CliError::usage)?;
+    core::set_entry_filter(filter);
+    Ok(())
+}
+
+fn cmd_install_mod(
+    zip_path: Option<&str>,
+    url: Option<&str>,
+    sha256: Option<&str>,
+    target_dir: &str,
+    clean: bool,
+    allow_risky: bool,
+    json: bool,
+) -> Result<(), CliError> {
+    validate_target_dir(target_dir)?;
+    let required = zip_path.filter(|_| url.is_none()).map(core::archive_install_size).unwrap_or(0);
+    core::preflight_check(target_dir, required).map_err(|e| CliError::failed(e.to_string()))?;
+    let report = match (zip_path, url) {
+        (_, Some(url)) => core::install_mod_from_url(url, target_dir, clean, sha256, allow_risky),
+        (Some(zip_path), None) => {
+            if !Path::new(zip_path).is_file() {
+                return Err(CliError::usage(format!("Mod archive not found: {}", zip_path)));
+            }
+            let risky = if allow_risky { Vec::new() } else { core::scan_archive_for_risky_files(zip_path) };
+            if risky.is_empty() {
+                core::install_mod_from_zip(zip_path, target_dir, clean)
+            } else {
+                Err(core::RiskyArchiveError { entries: risky }.into())
+            }
+        }
+        (None, None) => return Err(CliError::usage("Pass either --zip-path or --url")),
+    }
+    .map_err(|e| {
+        if let Some(entries) = core::risky_entries(e.as_ref()) {
+            return CliError {
+                details: Some(serde_json::json!({ "risky_entries": entries })),
+                ..CliError::failed(format!("Refusing to install: {} Re-run with --allow-risky to install anyway.", e))
+            };
+        }
+        let message = format!("Failed to install mod: {}", e);
+        // A local file that isn't a usable zip is bad input; a bad download is a failure
+        if url.is_none() && core::is_archive_error(e.as_ref()) {
+            CliError::usage(message)
+        } else {
+            CliError::failed(message)
+        }
+    })?;
+    let failed: Vec<serde_json::Value> = report
+        .failed
+        .iter()
+        .map(|(entry, error)| serde_json::json!({ "entry": entry, "error": error }))
+        .collect();
+    let details = serde_json::json!({
+        "installed": report.written.len(),
+        "failed": failed,
+        "bundled_mods": report.sub_mods,
+        "skipped": report.skipped,
+    });
+    if !report.is_complete() {
+        return Err(CliError {
+            details: Some(details),
+            ..CliError::failed(format!("Mod partially installed: {}", report.summary()))
+        });
+    }
+    if json {
+        let mut output = serde_json::json!({ "status": "ok" });
+        if let (Some(object), serde_json::Value::Object(details)) = (output.as_object_mut(), details) {
+            object.extend(details);
+        }
+        print_json(output);
+    } else {
+        for sub_mod in &report.sub_mods {
+            println!("Installed bundled mod: {}", sub_mod);
+        }
+        println!("Mod installed successfully.");
+    }
+    Ok(())
+}
+
+fn cmd_list_mods(target_dir: &str, json: bool) -> Result<(), CliError> {
+    validate_target_dir(target_dir)?;
+    let mods = core::list_installed_mods(target_dir).map_err(|e| CliError::failed(format!("Error listing mods: {}", e)))?;
+    let mismatches: Vec<String> = core::check_version_compat(target_dir).iter().map(ToString::to_string).collect();
+    if json {
+        print_json(serde_json::json!({ "mods": mods, "version_warnings": mismatches }));
+    } else if mods.is_empty() {
+        println!("No mods installed.");
+    } else {
+        println!("Installed mods:");
+        for m in mods {
+            println!("- {}", m);
+        }
+        for mismatch in mismatches {
+            println!("Warning: {}", mismatch);
+        }
+    }
+    Ok(())
+}
+
+/// Environment variables that change where the manager looks or how it downloads
+const CONFIG_ENV_VARS: &[&str] = &[config::CONFIG_DIR_VAR, config::CACHE_DIR_VAR, config::BACKUP_DIR_VAR, config::LOG_DIR_VAR, "HTTP_PROXY", "HTTPS_PROXY"];
+
+fn cmd_config(config: &Config, target_dir: Option<&str>, json: bool) -> Result<(), CliError> {
+    let mut settings = serde_json::to_value(config).map_err(|e| CliError::failed(format!("Failed to read the configuration: {}", e)))?;
+    // The output is meant to be pasted into support threads, so hide proxy passwords and API keys
+    if let serde_json::Value::Object(settings) = &mut settings {
+        for value in settings.values_mut() {
+            if let serde_json::Value::String(text) = value {
+                *text = core::scrub_secrets(text);
+            }
+        }
+    }
+    let mut paths = vec![
+        ("config_file", config::config_path()),
+        ("cache_file", cache_path()),
+        ("log_file", logging::log_path()),
+        ("download_cache", core::download_cache_dir()),
+    ];
+    if let Some(target_dir) = target_dir {
+        validate_target_dir(target_dir)?;
+        paths.extend([
+            ("mods", core::mods_dir(target_dir)),
+            ("logic_mods", core::logic_mods_dir(target_dir)),
+            ("paks", core::paks_dir(target_dir)),
+            ("ue4ss", core::ue4ss_dll_path(target_dir)),
+            ("backups", core::backup_dir(target_dir)),
+        ]);
+    }
+    let environment: Vec<(&str, String)> = CONFIG_ENV_VARS
+        .iter()
+        .filter_map(|&name| std::env::var(name).ok().filter(|value| !value.is_empty()).map(|value| (name, core::scrub_secrets(&value))))
+        .collect();
+    if json {
+        let paths: serde_json::Map<String, serde_json::Value> =
+            paths.iter().map(|(name, path)| (name.to_string(), path.display().to_string().into())).collect();
+        let environment: serde_json::Map<String, serde_json::Value> =
+            environment.into_iter().map(|(name, value)| (name.to_string(), value.into())).collect();
+        print_json(serde_json::json!({ "settings": settings, "paths": paths, "environment": environment }));
+        return Ok(());
+    }
+    println!("Settings:");
+    if let serde_json::Value::Object(settings) = &settings {
+        for (key, value) in settings {
+            match value {
+                serde_json::Value::String(text) => println!("  {} = {}", key, text),
+                other => println!("  {} = {}", key, other),
+            }
+        }
+    }
+    println!("Paths:");
+    for (name, path) in &paths {
+        let missing = if path.exists() { "" } else { " (does not exist yet)" };
+        println!("  {} = {}{}", name, path.display(), missing);
+    }
+    println!("Environment:");
+    if environment.is_empty() {
+        println!("  (no overrides set)");
+    }
+    for (name, value) in environment {
+        println!("  {} = {}", name, value);
+    }
+    Ok(())
+}
+
+fn cmd_version(json: bool) {
+    if json {
+        print_json(serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "ue4ss_version": core::pinned_ue4ss_version(),
+        }));
+    } else {
+        println!("UnnieModManager {}", env!("CARGO_PKG_VERSION"));
+        println!("Installs UE4SS {}", core::pinned_ue4ss_version());
+    }
+}
+
+fn cmd_reset_vanilla(target_dir: &str, logic_mods: bool, remove_ue4ss: bool, json: bool) -> Result<(), CliError> {
+    validate_target_dir(target_dir)?;
+    let report = core::remove_all_mods(target_dir, logic_mods, remove_ue4ss)
+        .map_err(|e| CliError::failed(format!("Failed to reset to vanilla: {}", e)))?;
+    if json {
+        print_json(serde_json::json!({
+            "status": "ok",
+            "backup": report.backup_path.display().to_string(),
+            "removed": report.removed,
+        }));
+    } else {
+        println!("Backup saved to {}", report.backup_path.display());
+        for name in &report.removed {
+            println!("- removed {}", name);
+        }
+        println!("Reset to vanilla complete.");
+    }
+    Ok(())
+}
+
+fn cmd_update_all(target_dir: &str, json: bool) -> Result<(), CliError> {
+    validate_target_dir(target_dir)?;
+    let results = core::update_all_mods(target_dir);
+    let failed = results.iter().filter(|(_, result)| update_failed(result)).count();
+    if json {
+        let mods: Vec<serde_json::Value> = results
+            .iter()
+            .map(|(name, result)| serde_json::json!({ "mod": name, "result": result.to_string() }))
+            .collect();
+        if failed > 0 {
+            return Err(CliError {
+                details: Some(serde_json::json!({ "mods": mods })),
+                ..CliError::failed(format!("{} mod(s) failed to update", failed))
+            });
+        }
+        print_json(serde_json::json!({ "status": "ok", "mods": mods }));
+    } else {
+        for (name, result) in &results {
+            println!("- {}: {}", name, result);
+        }
+        if failed > 0 {
+            return Err(CliError::failed(format!("{} mod(s) failed to update", failed)));
+        }
+    }
+    Ok(())
+}
+
+fn cmd_validate_mod(zip_path: &str, json: bool) -> Result<(), CliError> {
+    if !Path::new(zip_path).is_file() {
+        return Err(CliError::usage(format!("Mod archive not found: {}", zip_path)));
+    }
+    let issues = core::validate_mod_package(zip_path);
+    let errors = issues.iter().filter(|issue| issue.is_error()).count();
+    let warnings = issues.len() - errors;
+    if json {
+        let issues: Vec<serde_json::Value> = issues
+            .iter()
+            .map(|issue| serde_json::json!({ "level": if issue.is_error() { "error" } else { "warning" }, "message": issue.to_string() }))
+            .collect();
+        if errors > 0 {
+            return Err(CliError {
+                details: Some(serde_json::json!({ "issues": issues })),
+                ..CliError::failed(format!("{} packaging problem(s) found", errors))
+            });
         }
+        print_json(serde_json::json!({ "status": "ok", "issues": issues }));
+        return Ok(());
+    }
+    for issue in &issues {
+        let level = if issue.is_error() { "Error" } else { "Warning" };
+        println!("- {}: {}", level, issue);
+    }
+    if errors > 0 {
+        return Err(CliError::failed(format!("FAIL: {} packaging problem(s) found in {}", errors, zip_path)));
+    }
+    if warnings > 0 {
+        println!("PASS: {} is packaged correctly ({} warning(s))", zip_path, warnings);
+    } else {
+        println!("PASS: {} is packaged correctly", zip_path);
+    }
+    Ok(())
+}
+
+/// True if an update ended in an error or a partial install
+fn update_failed(result: &core::UpdateResult) -> bool {
+    match result {
+        core::UpdateResult::Failed(_) => true,
+        core::UpdateResult::Updated(report) => !report.is_complete(),
+        core::UpdateResult::UpToDate | core::UpdateResult::ManualUpdateRequired => false,
+    }
+}
+
+/// Print a machine-readable result for `--json` mode
+fn print_json(value: serde_json::Value) {
+    println!("{}", value);
+}
+
+/// Fonts with CJK glyphs that ship with Windows, tried in order when no font is configured
+const SYSTEM_CJK_FONTS: &[&str] = &[
+    "C:\\Windows\\Fonts\\msyh.ttc",
+    "C:\\Windows\\Fonts\\YuGothM.ttc",
+    "C:\\Windows\\Fonts\\meiryo.ttc",
+    "C:\\Windows\\Fonts\\malgun.ttf",
+    "C:\\Windows\\Fonts\\simsun.ttc",
+];
+
+/// A CJK font compiled into the binary with the `embedded-cjk-font` feature, read from the
+/// absolute path in `UNNIE_CJK_FONT` at build time
+#[cfg(feature = "embedded-cjk-font")]
+const EMBEDDED_CJK_FONT: Option<&[u8]> = Some(include_bytes!(env!(
+    "UNNIE_CJK_FONT",
+    "the embedded-cjk-font feature needs UNNIE_CJK_FONT set to the absolute path of a .ttf or .otf font with CJK glyphs"
+)));
+#[cfg(not(feature = "embedded-cjk-font"))]
+const EMBEDDED_CJK_FONT: Option<&[u8]> = None;
+
+/// Add a CJK-capable font as a fallback after egui's default fonts, so Japanese, Chinese, and
+/// Korean mod names don't render as boxes. Uses the configured `font_path`, else the embedded
+/// font if built in, else the first Windows system font found.
+fn install_fallback_font(ctx: &egui::Context, font_path: Option<&str>) {
+    let data = match font_path {
+        Some(path) => match fs::read(path) {
+            Ok(data) => Some(data),
+            Err(e) => {
+                log::error!("Failed to load font {}: {}", path, e);
+                None
+            }
+        },
+        None => None,
+    };
+    let data = data
+        .or_else(|| EMBEDDED_CJK_FONT.map(|font| font.to_vec()))
+        .or_else(|| SYSTEM_CJK_FONTS.iter().find_map(|path| fs::read(path).ok()));
+    let data = match data {
+        Some(data) => data,
+        None => return,
+    };
+    let mut fonts = egui::FontDefinitions::default();
+    fonts.font_data.insert("cjk_fallback".to_string(), egui::FontData::from_owned(data));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts.families.entry(family).or_default().push("cjk_fallback".to_string());
     }
+    ctx.set_fonts(fonts);
 }
 
 fn run_gui() {
-    let native_options = eframe::NativeOptions::default();
+    let cache = load_cache();
+    let mut viewport = egui::ViewportBuilder::default();
+    let mut centered = true;
+    if let Some(geometry) = cache.window {
+        viewport = viewport.with_inner_size(geometry.size);
+        if let Some(pos) = geometry.pos {
+            viewport = viewport.with_position(pos);
+            centered = false;
+        }
+    }
+    let native_options = eframe::NativeOptions {
+        viewport,
+        centered,
+        ..Default::default()
+    };
     eframe::run_native(
         "Expedition 33 UnnieModManager",
         native_options,
-        Box::new(|_cc| Box::new(GuiApp::default())),
+        Box::new(|cc| {
+            let app = GuiApp::default();
+            install_fallback_font(&cc.egui_ctx, app.config.font_path.as_deref());
+            Box::new(app)
+        }),
     ).unwrap();
 }
 
+/// Show a mod readme with light markdown formatting: headings, bullets, and code blocks.
+/// Plain-text readmes pass through unchanged.
+fn readme_ui(ui: &mut egui::Ui, text: &str) {
+    let mut in_code = false;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code = !in_code;
+            continue;
+        }
+        if in_code {
+            ui.label(egui::RichText::new(line).monospace());
+        } else if trimmed.starts_with('#') {
+            let level = trimmed.chars().take_while(|&c| c == '#').count();
+            let heading = trimmed[level..].trim();
+            let size = match level {
+                1 => 24.0,
+                2 => 22.0,
+                _ => 20.0,
+            };
+            ui.add_space(4.0);
+            ui.label(egui::RichText::new(heading).strong().size(size));
+        } else if let Some(item) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            ui.label(format!("  • {}", item.replace("**", "").replace('`', "")));
+        } else if trimmed.is_empty() {
+            ui.add_space(6.0);
+        } else {
+            ui.label(line.replace("**", "").replace('`', ""));
+        }
+    }
+}
+
+/// Header for an installed mod: a colored type badge, an icon showing whether the manager
+/// installed it, and the name, in `highlight` if selected
+fn mod_title(ui: &egui::Ui, name: &str, kind: core::ModKind, managed: bool, highlight: Option<egui::Color32>) -> egui::text::LayoutJob {
+    let badge_color = match kind {
+        core::ModKind::Lua => egui::Color32::from_rgb(60, 130, 200),
+        core::ModKind::Cpp => egui::Color32::from_rgb(150, 90, 200),
+        core::ModKind::Blueprint => egui::Color32::from_rgb(40, 150, 90),
+        core::ModKind::Pak => egui::Color32::from_rgb(200, 130, 40),
+        core::ModKind::Unknown => egui::Color32::GRAY,
+    };
+    let font_id = egui::TextStyle::Body.resolve(ui.style());
+    let mut job = egui::text::LayoutJob::default();
+    job.append(
+        &format!(" {} ", kind.label()),
+        0.0,
+        egui::TextFormat { font_id: font_id.clone(), color: egui::Color32::WHITE, background: badge_color, ..Default::default() },
+    );
+    let icon = if managed { "📦" } else { "✋" };
+    job.append(icon, 6.0, egui::TextFormat { font_id: font_id.clone(), color: egui::Color32::GRAY, ..Default::default() });
+    job.append(
+        name,
+        4.0,
+        egui::TextFormat { font_id, color: highlight.unwrap_or_else(|| ui.visuals().text_color()), ..Default::default() },
+    );
+    job
+}
+
+/// How long ago `time` was, in the largest whole unit, e.g. "3 days ago"
+fn format_ago(time: SystemTime) -> String {
+    let secs = SystemTime::now().duration_since(time).map(|elapsed| elapsed.as_secs()).unwrap_or(0);
+    let (count, unit) = match secs {
+        0..=59 => return t("time.just_now"),
+        60..=3599 => (secs / 60, "minute"),
+        3600..=86_399 => (secs / 3600, "hour"),
+        86_400..=2_591_999 => (secs / 86_400, "day"),
+        2_592_000..=31_535_999 => (secs / 2_592_000, "month"),
+        _ => (secs / 31_536_000, "year"),
+    };
+    let key = if count == 1 { format!("time.{}_ago", unit) } else { format!("time.{}s_ago", unit) };
+    t(&key).replace("{count}", &count.to_string())
+}
+
+/// Draw `files`, sorted by path and all under the folder `prefix`, as a tree of collapsible
+/// folders with the size of each file and folder. Missing files are shown in red, and files
+/// added after install (when `has_manifest`) in gray.
+fn file_tree_ui(ui: &mut egui::Ui, files: &[core::ModFile], prefix: &str, has_manifest: bool) {
+    let mut index = 0;
+    while index < files.len() {
+        let file = &files[index];
+        let rest = &file.path[prefix.len()..];
+        match rest.split_once('/') {
+            Some((folder, _)) => {
+                // Sorting by path keeps each folder's files together
+                let folder_prefix = format!("{}{}/", prefix, folder);
+                let end = files[index..]
+                    .iter()
+                    .position(|file| !file.path.starts_with(&folder_prefix))
+                    .map(|offset| index + offset)
+                    .unwrap_or(files.len());
+                let group = &files[index..end];
+                let size: u64 = group.iter().filter_map(|file| file.size).sum();
+                egui::CollapsingHeader::new(format!("📁 {} ({})", folder, core::format_size(size)))
+                    .id_source(&folder_prefix)
+                    .default_open(prefix.is_empty())
+                    .show(ui, |ui| file_tree_ui(ui, group, &folder_prefix, has_manifest));
+                index = end;
+            }
+            None => {
+                ui.horizontal(|ui| match file.size {
+                    Some(size) => {
+                        let text = egui::RichText::new(format!("{} ({})", rest, core::format_size(size)));
+                        if has_manifest && !file.in_manifest {
+                            ui.label(text.color(egui::Color32::GRAY)).on_hover_text(t("files.not_installed"));
+                        } else {
+                            ui.label(text);
+                        }
+                    }
+                    None => {
+                        ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {} — {}", rest, t("files.missing")));
+                    }
+                });
+                index += 1;
+            }
+        }
+    }
+}
+
+/// Tooltip text for a button with a keyboard shortcut; Ctrl is Cmd on macOS
+fn shortcut_hint(keys: &str) -> String {
+    let keys = if cfg!(target_os = "macos") { keys.replace("Ctrl", "Cmd") } else { keys.to_string() };
+    format!("{} ({})", t("shortcuts.hint"), keys)
+}
+
+/// Open a folder in the system's file explorer, or a file in its default app
+fn open_in_file_manager(path: &Path) {
+    #[cfg(windows)]
+    {
+        let _ = std::process::Command::new("explorer").arg(path).spawn();
+    }
+    #[cfg(target_os = "macos")]
+    {
+        let _ = std::process::Command::new("open").arg(path).spawn();
+    }
+    #[cfg(target_os = "linux")]
+    {
+        let _ = std::process::Command::new("xdg-open").arg(path).spawn();
+    }
+}
+
+/// Show a derived path without the `..` hops, resolving it when it already exists
+fn display_path(path: &Path) -> String {
+    let resolved = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let shown = resolved.display().to_string();
+    shown.strip_prefix(r"\\?\").map(str::to_string).unwrap_or(shown)
+}
+
+/// How a background UE4SS install ended, passed back from the worker thread
+enum Ue4ssOutcome {
+    /// Installed, with the directory scan taken afterwards
+    Installed(Vec<String>),
+    Cancelled,
+    Failed(String),
+}
+
+/// A UE4SS install running on a worker thread so the UI stays responsive and can cancel it
+struct Ue4ssTask {
+    cancel: Arc<AtomicBool>,
+    handle: thread::JoinHandle<Ue4ssOutcome>,
+    /// Entries walked by the post-install directory scan, sent once the scan starts
+    scan_progress: mpsc::Receiver<usize>,
+    /// Latest scan progress; `None` while still downloading and installing
+    scanned: Option<usize>,
+}
+
+/// How a background mod download ended, passed back from the worker thread
+enum DownloadOutcome {
+    Installed(core::InstallReport),
+    /// Files a UE4SS install would write
+    Previewed(Vec<PathBuf>),
+    /// Held back because the archive has executables or scripts: the reason and those entries
+    Risky(String, Vec<String>),
+    Cancelled,
+    Failed(String),
+}
+
+impl DownloadOutcome {
+    fn from_install(result: Result<core::InstallReport, Box<dyn std::error::Error>>) -> Self {
+        match result {
+            Ok(report) => DownloadOutcome::Installed(report),
+            Err(e) if core::is_cancelled(e.as_ref()) => DownloadOutcome::Cancelled,
+            Err(e) => match core::risky_entries(e.as_ref()) {
+                Some(entries) => DownloadOutcome::Risky(e.to_string(), entries.to_vec()),
+                None => DownloadOutcome::Failed(e.to_string()),
+            },
+        }
+    }
+}
+
+/// A mod or UE4SS download running on a worker thread so the UI stays responsive and can cancel it
+struct DownloadTask {
+    /// The task it runs for, to report the outcome against
+    kind: TaskKind,
+    cancel: Arc<AtomicBool>,
+    /// Bytes received so far
+    downloaded: Arc<AtomicU64>,
+    handle: thread::JoinHandle<DownloadOutcome>,
+}
+
+/// The backup browser: backups found for the game directory and the mods picked from the selected one
+struct BackupBrowser {
+    backups: Vec<PathBuf>,
+    selected: Option<usize>,
+    contents: Vec<String>,
+    picked: HashSet<String>,
+}
+
+/// The Browse Mods window: the fetched mod index, or why it couldn't be fetched
+struct ModBrowser {
+    /// `None` while the index is still being fetched
+    mods: Option<Result<Vec<core::IndexedMod>, String>>,
+    /// The fetch, running on a worker thread so the window stays responsive
+    fetch: Option<thread::JoinHandle<Result<Vec<core::IndexedMod>, String>>>,
+    filter: String,
+}
+
+/// Where an install waiting on the risky-files warning came from
+enum RiskySource {
+    Zips(Vec<String>, Option<core::InstallTarget>),
+    Url(String),
+}
+
+/// An install held back because its archives contain executables or scripts
+struct PendingRisky {
+    source: RiskySource,
+    /// Archive name and its risky entries
+    entries: Vec<(String, Vec<String>)>,
+}
+
+/// Archives with install options, waiting for the user to pick one option per group
+struct PendingOptions {
+    zip_paths: Vec<String>,
+    unknown_target: Option<core::InstallTarget>,
+    /// Archive path, the options it declares, and the option picked in each group so far
+    archives: Vec<(String, core::InstallOptions, Vec<usize>)>,
+}
+
+/// An install held back because it would overwrite existing files
+struct PendingOverwrite {
+    zip_paths: Vec<String>,
+    unknown_target: Option<core::InstallTarget>,
+    /// Archive file name and what installing it would change
+    diffs: Vec<(String, core::InstallDiff)>,
+}
+
+/// Watches the Mods folder so the installed list refreshes when it's changed outside the app
+struct ModsWatcher {
+    path: PathBuf,
+    /// Kept alive for as long as the folder should be watched; dropping it stops the watch
+    _watcher: notify::RecommendedWatcher,
+    events: mpsc::Receiver<()>,
+}
+
+impl ModsWatcher {
+    fn new(path: PathBuf, ctx: &egui::Context) -> notify::Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let ctx = ctx.clone();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok() && tx.send(()).is_ok() {
+                ctx.request_repaint();
+            }
+        })?;
+        notify::Watcher::watch(&mut watcher, &path, notify::RecursiveMode::NonRecursive)?;
+        Ok(Self { path, _watcher: watcher, events })
+    }
+}
+
+/// Action picked from an installed mod's context menu, applied once the list has been drawn
+enum ModMenuAction {
+    SetEnabled(String, bool),
+    SetFavorite(String, bool),
+    Reinstall(String),
+    Verify(String),
+    CompareToSource(String),
+    ShowFiles(String),
+    Duplicate(String),
+    Solo(String),
+    Uninstall(String),
+}
+
+/// An "Update All" running on a worker thread, reporting each mod's result as it finishes
+struct UpdateAllTask {
+    progress: mpsc::Receiver<String>,
+    handle: thread::JoinHandle<Vec<(String, core::UpdateResult)>>,
+    downloads: Arc<core::DownloadProgress>,
+    done: usize,
+    total: usize,
+}
+
+/// Background operations that run one at a time from the task queue
+#[derive(Clone, PartialEq)]
+enum TaskKind {
+    InstallUe4ss,
+    UpdateAll,
+    /// Download and install a mod; unless `allow_risky` is set, archives with executables or
+    /// scripts are held for confirmation
+    InstallFromUrl { url: String, allow_risky: bool },
+    /// Download a mod again from its recorded source URL and reinstall it
+    UpdateFromSource(String),
+    /// Download UE4SS to list the files it would install
+    PreviewUe4ss,
+}
+
+impl TaskKind {
+    fn label(&self) -> String {
+        match self {
+            TaskKind::InstallUe4ss => t("mods.install_ue4ss"),
+            TaskKind::UpdateAll => t("mods.update_all"),
+            TaskKind::InstallFromUrl { url, .. } => t("tasks.install_url").replace("{url}", url),
+            TaskKind::UpdateFromSource(name) => t("tasks.update_from_source").replace("{name}", name),
+            TaskKind::PreviewUe4ss => t("mods.preview_ue4ss"),
+        }
+    }
+}
+
+enum TaskStatus {
+    Queued,
+    Running,
+    Done,
+    Failed(String),
+    Cancelled,
+}
+
+/// An entry in the task list
+struct Task {
+    kind: TaskKind,
+    status: TaskStatus,
+}
+
+impl Task {
+    fn is_finished(&self) -> bool {
+        matches!(self.status, TaskStatus::Done | TaskStatus::Failed(_) | TaskStatus::Cancelled)
+    }
+}
+
+/// Finished tasks kept in the task list before the oldest are dropped
+const MAX_FINISHED_TASKS: usize = 10;
+
 struct GuiApp {
     win64_dir: String,
     debug_output: String,
     installed_mods: Vec<String>,
+    /// Editable source URL per mod, loaded lazily from each mod's manifest
+    source_urls: HashMap<String, String>,
+    /// Installed mods that carry the manager's marker, as opposed to ones placed by hand
+    managed_mods: HashSet<String>,
+    /// Totals shown above the installed mods list
+    mod_summary: Option<core::ModSummary>,
+    /// Mods pinned to the top of the installed list
+    favorites: HashSet<String>,
+    /// Mods UE4SS will load, refreshed with the mod list so drawing it doesn't re-read `mods.txt`
+    enabled_mods: HashSet<String>,
+    /// Installed mods listed in the compatibility database
+    compat_warnings: HashMap<String, core::CompatWarning>,
+    /// Detected type of each installed mod, classified lazily from its folder contents
+    mod_kinds: HashMap<String, core::ModKind>,
+    /// Per-mod notes being edited, loaded lazily from the notes sidecar file
+    notes: HashMap<String, String>,
+    /// Unsatisfied mod dependencies, refreshed with the mod list
+    dependency_warnings: Vec<core::MissingDependency>,
+    /// Whether UE4SS was found in the game directory at the last refresh
+    ue4ss_installed: bool,
+    /// When each mod was last installed or changed, by mod name
+    updated_at: HashMap<String, SystemTime>,
+    /// Mods declared for other game versions than the installed one, by mod name
+    version_mismatches: HashMap<String, core::VersionMismatch>,
     scanned_files: Vec<String>,
+    scanned_filter: String,
     cache: AppCache,
+    config: Config,
+    /// Name typed for "Save as Target", which saves the current directory under it
+    target_name_edit: String,
+    /// Text field contents for the Mods folder name, applied to `config` once validated
+    mods_folder_edit: String,
+    proxy_edit: String,
+    /// Comma-separated exclude patterns, applied to `config` once they parse
+    exclude_edit: String,
+    /// Direct download link typed into the "Install from URL" field
+    install_url: String,
     debug_mode: bool,
+    clean_install: bool,
+    /// Files a UE4SS install would write (relative path, already exists), shown in a preview window
+    ue4ss_preview: Option<Vec<(String, bool)>>,
+    mod_browser: Option<ModBrowser>,
+    /// Result of "Verify files" for a mod, shown in a window while set
+    integrity_report: Option<(String, Result<Vec<core::FileIntegrityIssue>, String>)>,
+    /// Mod whose files are listed in the "Show files" window, and those files
+    file_view: Option<(String, Vec<core::ModFile>)>,
+    /// Mod checked with "Validate against source", and how it compares with its archive
+    source_comparison: Option<(String, Result<core::ComparisonReport, String>)>,
+    /// Comparison of this setup with an exported mod list, and the file it came from
+    modlist_diff: Option<(String, core::ModlistDiff)>,
+    /// Mod whose readme is shown in the Info window, and the readme text if it has one
+    readme_view: Option<(String, Option<String>, Option<String>)>,
+    ue4ss_task: Option<Ue4ssTask>,
+    update_task: Option<UpdateAllTask>,
+    download_task: Option<DownloadTask>,
+    /// Queued, running, and recently finished background operations, oldest first
+    tasks: VecDeque<Task>,
+    mods_watcher: Option<ModsWatcher>,
+    /// Mod whose notes field should take keyboard focus the next time it's drawn
+    focus_note_for: Option<String>,
+    /// Whether the game was running at the last check; installs and uninstalls are blocked while it is
+    game_running: bool,
+    game_checked_at: Option<Instant>,
+    /// When the mods list was last re-read by auto-refresh
+    auto_refreshed_at: Instant,
+    /// Archives waiting for the user to pick where unrecognized mods go
+    pending_target_choice: Option<Vec<String>>,
+    /// Mods awaiting confirmation before they're uninstalled
+    confirm_uninstall: Option<Vec<String>>,
+    /// Mod being duplicated with "Duplicate...", and the name typed for the copy
+    clone_prompt: Option<(String, String)>,
+    /// The only mod left enabled by "Solo this mod", until the previous state is restored
+    solo_mod: Option<String>,
+    /// Mods ticked in the installed list for "Uninstall Selected"
+    checked_mods: HashSet<String>,
+    /// Archives that would overwrite installed files, waiting for the user to confirm
+    pending_overwrite: Option<PendingOverwrite>,
+    /// Install waiting for the user to accept the risky-files warning
+    pending_risky: Option<PendingRisky>,
+    /// Install waiting for the user to choose between the options an archive offers
+    pending_options: Option<PendingOptions>,
+    /// Options picked for the archives being installed, by archive path
+    install_choices: HashMap<String, Vec<usize>>,
+    /// Index into `installed_mods` highlighted by arrow-key navigation
+    selected_mod: Option<usize>,
+    /// Detected game version and the directory it was read from, so it's only re-read on change
+    game_version: Option<(String, Option<String>)>,
+    /// UE4SS settings and the directory they were read from; `None` inside when there's no settings file
+    ue4ss_settings: Option<(String, Option<core::Ue4ssSettings>)>,
+    /// Result of the last mods.txt validation, shown in a window while set
+    load_order_issues: Option<Vec<core::LoadOrderIssue>>,
+    confirm_load_order_fix: bool,
+    confirm_reset: bool,
+    /// Why the last install couldn't start, shown in a window while set
+    preflight_error: Option<String>,
+    /// Asking whether to close the window while a download or install is still running
+    confirm_exit: bool,
+    /// Close the window as soon as the running task finishes
+    exit_when_idle: bool,
+    /// Game directory switched away from while mods were ticked or tasks queued, until the user
+    /// decides whether those still apply to the new one
+    dir_change_prompt: Option<String>,
+    backup_browser: Option<BackupBrowser>,
+    reset_logic_mods: bool,
+    reset_remove_ue4ss: bool,
     ui_scale: f32,
+    geometry_checked: bool,
 }
 
 impl Default for GuiApp {
     fn default() -> Self {
         let cache = load_cache();
+        let config = config::load_config();
+        i18n::set_language(&config.language);
+        let installed = core::list_installed_mods_detailed(&cache.last_win64_dir).unwrap_or_default();
+        let solo_mod = core::solo_mod_name(&cache.last_win64_dir);
         Self {
             win64_dir: cache.last_win64_dir.clone(),
             debug_output: cache.last_debug_output.clone(),
             installed_mods: cache.last_installed_mods.clone(),
+            source_urls: HashMap::new(),
+            notes: HashMap::new(),
+            mod_kinds: HashMap::new(),
+            managed_mods: installed.iter().filter(|m| m.managed).map(|m| m.name.clone()).collect(),
+            favorites: installed.iter().filter(|m| m.favorite).map(|m| m.name.clone()).collect(),
+            enabled_mods: installed.iter().filter(|m| m.enabled).map(|m| m.name.clone()).collect(),
+            updated_at: installed.iter().filter_map(|m| m.updated.map(|updated| (m.name.clone(), updated))).collect(),
+            mod_summary: (!cache.last_win64_dir.is_empty()).then(|| core::summarize_mods(&cache.last_win64_dir, &installed)),
+            compat_warnings: installed
+                .into_iter()
+                .filter_map(|m| m.compat_warning.map(|warning| (m.name, warning)))
+                .collect(),
+            dependency_warnings: Vec::new(),
+            ue4ss_installed: core::detect_ue4ss(&cache.last_win64_dir),
+            version_mismatches: HashMap::new(),
             scanned_files: cache.last_scanned_files.clone(),
+            scanned_filter: String::new(),
             cache,
+            mods_folder_edit: config.mods_folder_name.clone(),
+            proxy_edit: config.proxy_url.clone().unwrap_or_default(),
+            exclude_edit: config.exclude_patterns.join(", "),
+            config,
+            target_name_edit: String::new(),
+            install_url: String::new(),
             debug_mode: false,
+            clean_install: false,
+            ue4ss_preview: None,
+            readme_view: None,
+            modlist_diff: None,
+            mod_browser: None,
+            integrity_report: None,
+            source_comparison: None,
+            file_view: None,
+            solo_mod,
+            ue4ss_task: None,
+            update_task: None,
+            download_task: None,
+            tasks: VecDeque::new(),
+            game_version: None,
+            ue4ss_settings: None,
+            selected_mod: None,
+            mods_watcher: None,
+            focus_note_for: None,
+            game_running: false,
+            game_checked_at: None,
+            auto_refreshed_at: Instant::now(),
+            confirm_uninstall: None,
+            clone_prompt: None,
+            checked_mods: HashSet::new(),
+            pending_target_choice: None,
+            pending_overwrite: None,
+            pending_risky: None,
+            pending_options: None,
+            install_choices: HashMap::new(),
+            load_order_issues: None,
+            confirm_load_order_fix: false,
+            confirm_reset: false,
+            preflight_error: None,
+            confirm_exit: false,
+            exit_when_idle: false,
+            dir_change_prompt: None,
+            backup_browser: None,
+            reset_logic_mods: false,
+            reset_remove_ue4ss: false,
             ui_scale: 1.0,
+            geometry_checked: false,
         }
     }
 }
@@ -157,10 +1235,68 @@ macro_rules! debug_println {
     };
 }
 
+/// Black backgrounds, white text, and thick outlines on every widget, with hovered and
+/// pressed widgets in yellow, for users who can't make out the default low-contrast widgets
+fn high_contrast_visuals() -> egui::Visuals {
+    let foreground = egui::Color32::WHITE;
+    let background = egui::Color32::BLACK;
+    let highlight = egui::Color32::from_rgb(255, 215, 0);
+    let mut visuals = egui::Visuals::dark();
+    visuals.panel_fill = background;
+    visuals.window_fill = background;
+    visuals.extreme_bg_color = background;
+    visuals.faint_bg_color = egui::Color32::from_gray(24);
+    visuals.window_stroke = egui::Stroke::new(2.0, foreground);
+    visuals.hyperlink_color = highlight;
+    visuals.selection.bg_fill = highlight;
+    visuals.selection.stroke = egui::Stroke::new(2.0, background);
+
+    let widgets = &mut visuals.widgets;
+    widgets.noninteractive.bg_stroke = egui::Stroke::new(1.5, foreground);
+    widgets.noninteractive.fg_stroke = egui::Stroke::new(1.5, foreground);
+    widgets.inactive.bg_fill = background;
+    widgets.inactive.weak_bg_fill = background;
+    widgets.inactive.bg_stroke = egui::Stroke::new(2.0, foreground);
+    widgets.inactive.fg_stroke = egui::Stroke::new(2.0, foreground);
+    widgets.hovered.bg_fill = egui::Color32::from_gray(40);
+    widgets.hovered.weak_bg_fill = egui::Color32::from_gray(40);
+    widgets.hovered.bg_stroke = egui::Stroke::new(3.0, highlight);
+    widgets.hovered.fg_stroke = egui::Stroke::new(2.0, highlight);
+    widgets.active.bg_fill = highlight;
+    widgets.active.weak_bg_fill = highlight;
+    widgets.active.bg_stroke = egui::Stroke::new(3.0, highlight);
+    widgets.active.fg_stroke = egui::Stroke::new(2.0, background);
+    widgets.open = widgets.hovered;
+    visuals
+}
+
 impl eframe::App for GuiApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Set a custom dark theme for better contrast
-        ctx.set_visuals(egui::Visuals::dark());
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        let visuals = match self.config.theme {
+            _ if self.config.high_contrast => high_contrast_visuals(),
+            Theme::Dark => egui::Visuals::dark(),
+            Theme::Light => egui::Visuals::light(),
+            Theme::System => frame
+                .info()
+                .system_theme
+                .map(|theme| theme.egui_visuals())
+                .unwrap_or_else(egui::Visuals::dark),
+        };
+        ctx.set_visuals(visuals);
+        self.poll_ue4ss_task();
+        self.poll_update_task();
+        self.poll_download_task();
+        self.poll_mod_browser();
+        self.poll_tasks();
+        self.sync_mods_watcher(ctx);
+        self.poll_game_running(ctx);
+        self.poll_auto_refresh(ctx);
+        let selection_moved = self.handle_shortcuts(ctx);
+        if self.task_running() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        } else if self.exit_when_idle {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+        }
         let mut style = (*ctx.style()).clone();
         style.text_styles = [
             (egui::TextStyle::Heading, egui::FontId::proportional(28.0)),
@@ -169,114 +1305,551 @@ impl eframe::App for GuiApp {
             (egui::TextStyle::Monospace, egui::FontId::monospace(18.0)),
             (egui::TextStyle::Small, egui::FontId::proportional(16.0)),
         ].into();
+        if self.config.high_contrast {
+            style.spacing.interact_size.y = 40.0;
+            style.spacing.button_padding = egui::vec2(12.0, 8.0);
+            style.spacing.item_spacing = egui::vec2(10.0, 10.0);
+            style.spacing.icon_width = 24.0;
+        }
         ctx.set_style(style);
 
-        // Accent color for buttons
-        let accent_color = egui::Color32::from_rgb(0, 120, 220); // blue accent
+        self.track_window_geometry(ctx);
+
+        // Accent color for buttons: blue, or yellow with black text in high contrast mode
+        let (accent_color, accent_text) = if self.config.high_contrast {
+            (egui::Color32::from_rgb(255, 215, 0), egui::Color32::BLACK)
+        } else {
+            (egui::Color32::from_rgb(0, 120, 220), egui::Color32::WHITE)
+        };
+        // Main buttons grow in high contrast mode so they're easier to hit
+        let button_scale = if self.config.high_contrast { 1.3 } else { 1.0 };
 
         egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
             ui.add_space(8.0);
-            ui.heading("Expedition 33 UnnieModManager");
+            ui.heading(t("app.title"));
             ui.add_space(8.0);
         });
 
         egui::SidePanel::left("left_panel").min_width(280.0).show(ctx, |ui| {
             ui.add_space(12.0);
             ui.group(|ui| {
-                ui.heading("Settings");
+                ui.heading(t("settings.heading"));
                 ui.add_space(8.0);
                 ui.horizontal(|ui| {
-                    ui.label("UI Scale:");
+                    ui.label(t("settings.ui_scale"));
                     let mut scale_changed = false;
                     scale_changed |= ui.add(egui::Slider::new(&mut self.ui_scale, 0.8..=2.0).text("")).changed();
                     if scale_changed {
                         ctx.set_pixels_per_point(self.ui_scale);
                     }
                 });
-                ui.checkbox(&mut self.debug_mode, "Debug Mode");
-            });
-            ui.add_space(16.0);
-            ui.group(|ui| {
-                ui.heading("Game Directory");
-                ui.add_space(8.0);
-                ui.label("Game Win64 Directory:");
-                let changed = ui.text_edit_singleline(&mut self.win64_dir).changed();
-                ui.add_space(4.0);
-                if ui.add_sized([220.0, 32.0], egui::Button::new("Select Win64 Directory")).clicked() {
-                    if let Some(dir) = rfd::FileDialog::new().pick_folder() {
-                        self.win64_dir = dir.display().to_string();
-                        debug_println!(self, "[INFO] Selected directory: {}\n", self.win64_dir);
-                        self.cache.last_win64_dir = self.win64_dir.clone();
-                        self.cache.last_debug_output = self.debug_output.clone();
-                        save_cache(&self.cache);
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.theme"));
+                    let previous_theme = self.config.theme;
+                    egui::ComboBox::from_id_source("theme_select")
+                        .selected_text(self.config.theme.label())
+                        .show_ui(ui, |ui| {
+                            for theme in Theme::ALL {
+                                ui.selectable_value(&mut self.config.theme, theme, theme.label());
+                            }
+                        });
+                    if self.config.theme != previous_theme {
+                        config::save_config(&self.config);
                     }
+                });
+                if ui.checkbox(&mut self.config.high_contrast, t("settings.high_contrast")).on_hover_text(t("settings.high_contrast_hint")).changed() {
+                    config::save_config(&self.config);
                 }
-                if changed {
-                    self.update_mod_list();
-                    self.cache.last_win64_dir = self.win64_dir.clone();
-                    self.cache.last_debug_output = self.debug_output.clone();
-                    save_cache(&self.cache);
-                }
-                ui.add_space(4.0);
-                ui.label(egui::RichText::new("Example game path: Expedition 33\\Sandfall\\Binaries\\Win64").color(egui::Color32::GRAY).italics());
-            });
-            ui.add_space(16.0);
-            ui.group(|ui| {
-                ui.heading("Mod Management");
-                ui.add_space(8.0);
-                let button_frame = |ui: &mut egui::Ui, text: &str| {
-                    ui.add_sized([
-                        220.0, 36.0
-                    ], egui::Button::new(egui::RichText::new(text).color(egui::Color32::WHITE)).fill(accent_color))
-                };
-                if button_frame(ui, "Install UE4SS").clicked() {
-                    self.debug_output.clear();
-                    if self.win64_dir.is_empty() {
-                        self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
-                    } else {
-                        debug_println!(self, "[INFO] Installing UE4SS...\n");
-                        match core::install_ue4ss(&self.win64_dir) {
-                            Ok(_) => {
-                                self.debug_output.push_str("[INFO] UE4SS installed successfully.\n");
-                                self.update_mod_list();
-                                let entries = core::list_all_files_and_dirs(&self.win64_dir).unwrap_or_default();
-                                self.scanned_files = entries;
-                            },
-                            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to install UE4SS: {}\n", e)),
-                        }
-                        self.cache.last_win64_dir = self.win64_dir.clone();
-                        self.cache.last_scanned_files = self.scanned_files.clone();
-                        self.cache.last_debug_output = self.debug_output.clone();
-                        save_cache(&self.cache);
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.unknown_target"));
+                    let previous_target = self.config.default_unknown_target;
+                    egui::ComboBox::from_id_source("unknown_target_select")
+                        .selected_text(self.config.default_unknown_target.label())
+                        .show_ui(ui, |ui| {
+                            for target in UnknownModTarget::ALL {
+                                ui.selectable_value(&mut self.config.default_unknown_target, target, target.label());
+                            }
+                        })
+                        .response
+                        .on_hover_text(t("settings.unknown_target_hint"));
+                    if self.config.default_unknown_target != previous_target {
+                        core::set_unknown_mod_target(self.config.default_unknown_target.install_target());
+                        config::save_config(&self.config);
+                    }
+                });
+                if ui
+                    .checkbox(&mut self.config.auto_enable_on_install, t("settings.auto_enable"))
+                    .on_hover_text(t("settings.auto_enable_hint"))
+                    .changed()
+                {
+                    core::set_auto_enable_on_install(self.config.auto_enable_on_install);
+                    config::save_config(&self.config);
+                }
+                if ui
+                    .checkbox(&mut self.config.flatten_wrapper_folders, t("settings.flatten_wrappers"))
+                    .on_hover_text(t("settings.flatten_wrappers_hint"))
+                    .changed()
+                {
+                    core::set_flatten_wrapper_folders(self.config.flatten_wrapper_folders);
+                    config::save_config(&self.config);
+                }
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.language"));
+                    let previous_language = self.config.language.clone();
+                    egui::ComboBox::from_id_source("language_select")
+                        .selected_text(i18n::language_name(&self.config.language))
+                        .show_ui(ui, |ui| {
+                            for (code, name) in i18n::languages() {
+                                ui.selectable_value(&mut self.config.language, code.to_string(), name);
+                            }
+                        });
+                    if self.config.language != previous_language {
+                        i18n::set_language(&self.config.language);
+                        config::save_config(&self.config);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.mods_folder"));
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.mods_folder_edit).desired_width(120.0));
+                    if response.lost_focus() && self.mods_folder_edit != self.config.mods_folder_name {
+                        match core::set_mods_folder_name(&self.mods_folder_edit) {
+                            Ok(()) => {
+                                self.config.mods_folder_name = self.mods_folder_edit.trim().to_string();
+                                config::save_config(&self.config);
+                                self.update_mod_list();
+                            }
+                            Err(e) => {
+                                self.debug_output.push_str(&format!("[ERROR] {}\n", e));
+                                self.mods_folder_edit = self.config.mods_folder_name.clone();
+                            }
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.proxy"));
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.proxy_edit).hint_text("http://host:port").desired_width(160.0));
+                    if response.lost_focus() {
+                        let proxy = Some(self.proxy_edit.trim().to_string()).filter(|p| !p.is_empty());
+                        if proxy != self.config.proxy_url {
+                            self.config.proxy_url = proxy;
+                            core::set_network_settings(self.config.network_settings());
+                            config::save_config(&self.config);
+                        }
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.download_timeout"));
+                    let response = ui
+                        .add(egui::DragValue::new(&mut self.config.download_timeout_secs).clamp_range(5..=600).suffix(" s"))
+                        .on_hover_text(t("settings.download_timeout_hint"));
+                    if response.changed() {
+                        core::set_network_settings(self.config.network_settings());
+                        config::save_config(&self.config);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.max_concurrent_downloads"));
+                    let response = ui
+                        .add(egui::DragValue::new(&mut self.config.max_concurrent_downloads).clamp_range(1..=8))
+                        .on_hover_text(t("settings.max_concurrent_downloads_hint"));
+                    if response.changed() {
+                        core::set_network_settings(self.config.network_settings());
+                        config::save_config(&self.config);
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label(t("settings.exclude_patterns"));
+                    let response = ui
+                        .add(egui::TextEdit::singleline(&mut self.exclude_edit).hint_text("*.txt, *.md").desired_width(160.0))
+                        .on_hover_text(t("settings.exclude_patterns_hint"));
+                    if response.lost_focus() {
+                        let patterns: Vec<String> = self
+                            .exclude_edit
+                            .split(',')
+                            .map(|p| p.trim().to_string())
+                            .filter(|p| !p.is_empty())
+                            .collect();
+                        if patterns != self.config.exclude_patterns {
+                            match core::EntryFilter::new(&self.config.include_patterns, &patterns) {
+                                Ok(filter) => {
+                                    core::set_entry_filter(filter);
+                                    self.config.exclude_patterns = patterns;
+                                    config::save_config(&self.config);
+                                }
+                                Err(e) => {
+                                    self.debug_output.push_str(&format!("[ERROR] {}\n", e));
+                                    self.exclude_edit = self.config.exclude_patterns.join(", ");
+                                }
+                            }
+                        }
+                    }
+                });
+                if let Some(url) = self.config.compat_db_url.clone() {
+                    if ui.button(t("settings.update_compat_db")).on_hover_text(url.as_str()).clicked() {
+                        match core::update_compat_db(&url) {
+                            Ok(count) => {
+                                self.debug_output.push_str(&format!("[INFO] Compatibility list updated: {} known problem mods.\n", count));
+                                self.update_mod_list();
+                            }
+                            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to update compatibility list: {}\n", e)),
+                        }
                     }
                 }
+                ui.horizontal(|ui| {
+                    if ui.checkbox(&mut self.config.cache_downloads, t("settings.cache_downloads")).on_hover_text(t("settings.cache_downloads_hint")).changed() {
+                        core::set_network_settings(self.config.network_settings());
+                        config::save_config(&self.config);
+                    }
+                    let clear = ui
+                        .button(t("settings.clear_cache"))
+                        .on_hover_ui(|ui| {
+                            ui.label(format!("{}: {}", t("settings.cache_size"), core::format_size(core::download_cache_size())));
+                        });
+                    if clear.clicked() {
+                        match core::clear_download_cache() {
+                            Ok(freed) => self.debug_output.push_str(&format!("[INFO] Download cache cleared, freed {}.\n", core::format_size(freed))),
+                            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to clear download cache: {}\n", e)),
+                        }
+                    }
+                });
+                ui.checkbox(&mut self.debug_mode, t("settings.debug_mode"));
+                ui.horizontal(|ui| {
+                    if ui.button(t("settings.export_report")).on_hover_text(t("settings.export_report_hint")).clicked() {
+                        self.export_debug_report();
+                    }
+                    if ui.button(t("settings.crash_report")).on_hover_text(t("settings.crash_report_hint")).clicked() {
+                        self.collect_crash_report();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    if ui.button(t("settings.export_modlist")).clicked() {
+                        self.export_modlist();
+                    }
+                    if ui.button(t("settings.compare_modlist")).on_hover_text(t("settings.compare_modlist_hint")).clicked() {
+                        self.compare_modlist();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    let config_path = config::config_path();
+                    let cache_path = cache_path();
+                    let folder = config_path.parent().map(Path::to_path_buf).unwrap_or_default();
+                    if ui.button(t("settings.open_config_folder")).on_hover_text(folder.display().to_string()).clicked() {
+                        open_in_file_manager(&folder);
+                    }
+                    if ui.button(t("settings.open_config")).on_hover_text(config_path.display().to_string()).clicked() {
+                        // Write the file first so there is something to open on a fresh install
+                        if !config_path.exists() {
+                            config::save_config(&self.config);
+                        }
+                        open_in_file_manager(&config_path);
+                    }
+                    if ui.button(t("settings.open_cache")).on_hover_text(cache_path.display().to_string()).clicked() {
+                        if !cache_path.exists() {
+                            save_cache(&self.cache);
+                        }
+                        open_in_file_manager(&cache_path);
+                    }
+                });
+            });
+            ui.add_space(16.0);
+            ui.group(|ui| {
+                ui.heading(t("game_dir.heading"));
                 ui.add_space(8.0);
-                if button_frame(ui, "Install Mod").clicked() {
-                    self.debug_output.clear();
-                    if self.win64_dir.is_empty() {
-                        self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
-                    } else if let Some(zip_path) = rfd::FileDialog::new().add_filter("Zip files", &["zip"]).pick_file() {
-                        let path_str = zip_path.display().to_string();
-                        let file_name = zip_path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                        debug_println!(self, "[INFO] Selected mod zip: {}\n", path_str);
-                        match core::install_mod_from_zip(&path_str, &self.win64_dir) {
-                            Ok(_) => self.debug_output.push_str(&format!("[INFO] Mod '{}' installed successfully.\n", file_name)),
-                            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to install mod '{}': {}\n", file_name, e)),
+                let previous_dir = self.win64_dir.clone();
+                let mut changed = false;
+                ui.horizontal(|ui| {
+                    ui.label(t("game_dir.target"));
+                    let current = self.config.target_for_dir(&self.win64_dir).map(|target| target.name.clone());
+                    let mut picked = None;
+                    egui::ComboBox::from_id_source("game_targets")
+                        .selected_text(current.clone().unwrap_or_else(|| t("game_dir.no_target")))
+                        .show_ui(ui, |ui| {
+                            if self.config.targets.is_empty() {
+                                ui.label(egui::RichText::new(t("game_dir.no_targets")).color(egui::Color32::GRAY));
+                            }
+                            for target in &self.config.targets {
+                                let selected = current.as_deref() == Some(target.name.as_str());
+                                if ui.selectable_label(selected, &target.name).on_hover_text(&target.win64_dir).clicked() {
+                                    picked = Some(target.win64_dir.clone());
+                                }
+                            }
+                        });
+                    if let Some(dir) = picked {
+                        self.cache.remember_dir(&dir);
+                        self.win64_dir = dir;
+                        changed = true;
+                    }
+                    ui.add(egui::TextEdit::singleline(&mut self.target_name_edit).hint_text(t("game_dir.target_name")).desired_width(120.0));
+                    let can_save = !self.target_name_edit.trim().is_empty() && !self.win64_dir.trim().is_empty();
+                    if ui
+                        .add_enabled(can_save, egui::Button::new(t("game_dir.save_target")))
+                        .on_hover_text(t("game_dir.save_target_hint"))
+                        .clicked()
+                    {
+                        let name = self.target_name_edit.trim().to_string();
+                        self.config.set_target(&name, &self.win64_dir);
+                        config::save_config(&self.config);
+                        self.target_name_edit.clear();
+                        self.debug_output.push_str(&format!("[INFO] Saved target '{}' for {}\n", name, self.win64_dir));
+                    }
+                    if let Some(name) = current {
+                        if ui.button(t("game_dir.remove_target")).clicked() {
+                            self.config.targets.retain(|target| target.name != name);
+                            config::save_config(&self.config);
                         }
-                        self.update_mod_list();
-                        self.cache.last_win64_dir = self.win64_dir.clone();
-                        self.cache.last_installed_mods = self.installed_mods.clone();
-                        self.cache.last_debug_output = self.debug_output.clone();
+                    }
+                });
+                ui.add_space(4.0);
+                ui.label(t("game_dir.label"));
+                ui.horizontal(|ui| {
+                    let response = ui.text_edit_singleline(&mut self.win64_dir);
+                    changed |= response.changed();
+                    // Only remember typed paths once editing is done, not every keystroke
+                    if response.lost_focus() && Path::new(&self.win64_dir).is_dir() {
+                        let dir = self.win64_dir.clone();
+                        self.cache.remember_dir(&dir);
                         save_cache(&self.cache);
                     }
+                    let mut picked = None;
+                    egui::ComboBox::from_id_source("recent_dirs")
+                        .selected_text(t("game_dir.recent"))
+                        .show_ui(ui, |ui| {
+                            if self.cache.recent_win64_dirs.is_empty() {
+                                ui.label(egui::RichText::new(t("game_dir.no_recent")).color(egui::Color32::GRAY));
+                            }
+                            for dir in &self.cache.recent_win64_dirs {
+                                let exists = Path::new(dir).is_dir();
+                                let response = ui
+                                    .add_enabled(exists, egui::SelectableLabel::new(dir == &self.win64_dir, dir.as_str()))
+                                    .on_disabled_hover_text(t("game_dir.missing"));
+                                if response.clicked() {
+                                    picked = Some(dir.clone());
+                                }
+                            }
+                        });
+                    if let Some(dir) = picked {
+                        self.cache.remember_dir(&dir);
+                        self.win64_dir = dir;
+                        changed = true;
+                    }
+                });
+                ui.add_space(4.0);
+                if ui
+                    .add_sized([220.0 * button_scale, 32.0 * button_scale], egui::Button::new(t("game_dir.select")))
+                    .on_hover_text(shortcut_hint("Ctrl+O"))
+                    .clicked()
+                {
+                    self.select_directory();
+                }
+                if changed {
+                    self.note_dir_change(previous_dir);
+                    self.update_mod_list();
+                    self.cache.last_win64_dir = self.win64_dir.clone();
+                    self.cache.last_debug_output = self.debug_output.clone();
+                    save_cache(&self.cache);
+                }
+                ui.add_space(4.0);
+                ui.label(egui::RichText::new(t("game_dir.example")).color(egui::Color32::GRAY).italics());
+            });
+            if !self.win64_dir.is_empty() {
+                ui.add_space(16.0);
+                ui.group(|ui| {
+                    ui.heading(t("paths.heading"));
+                    ui.add_space(8.0);
+                    match self.detected_game_version() {
+                        Some(version) => {
+                            ui.label(format!("{}: {}", t("paths.game_version"), version));
+                        }
+                        None => {
+                            ui.label(egui::RichText::new(format!("{}: {}", t("paths.game_version"), t("paths.unknown"))).color(egui::Color32::GRAY));
+                        }
+                    }
+                    let paths = [
+                        (t("paths.mods"), core::mods_dir(&self.win64_dir)),
+                        (t("paths.logic_mods"), core::logic_mods_dir(&self.win64_dir)),
+                        (t("paths.paks"), core::paks_dir(&self.win64_dir)),
+                        (t("paths.ue4ss"), core::ue4ss_dll_path(&self.win64_dir)),
+                        (t("paths.backups"), core::backup_dir(&self.win64_dir)),
+                        (t("paths.log"), logging::log_path()),
+                        (t("paths.config"), config::config_path()),
+                        (t("paths.cache"), cache_path()),
+                        (t("paths.download_cache"), core::download_cache_dir()),
+                    ];
+                    for (label, path) in paths {
+                        let exists = path.exists();
+                        let shown = display_path(&path);
+                        ui.horizontal(|ui| {
+                            if ui.small_button(t("paths.copy")).on_hover_text(t("paths.copy_hint")).clicked() {
+                                ui.output_mut(|o| o.copied_text = shown.clone());
+                            }
+                            let text = format!("{}: {}", label, shown);
+                            let text = if exists {
+                                egui::RichText::new(text)
+                            } else {
+                                egui::RichText::new(text).color(egui::Color32::GRAY)
+                            };
+                            let response = ui.label(text);
+                            if !exists {
+                                response.on_hover_text(t("paths.missing"));
+                            }
+                        });
+                    }
+                });
+                let stale = match &self.ue4ss_settings {
+                    Some((dir, _)) => dir != &self.win64_dir,
+                    None => true,
+                };
+                if stale {
+                    let settings = core::read_ue4ss_settings(&self.win64_dir).ok();
+                    self.ue4ss_settings = Some((self.win64_dir.clone(), settings));
+                }
+                if let Some((_, Some(settings))) = &self.ue4ss_settings {
+                    let mut edited = settings.clone();
+                    ui.add_space(16.0);
+                    ui.group(|ui| {
+                        ui.heading(t("ue4ss_settings.heading"));
+                        ui.add_space(8.0);
+                        ui.checkbox(&mut edited.console_enabled, t("ue4ss_settings.console"));
+                        ui.checkbox(&mut edited.gui_console_enabled, t("ue4ss_settings.gui_console"));
+                        ui.add_enabled(edited.gui_console_enabled, egui::Checkbox::new(&mut edited.gui_console_visible, t("ue4ss_settings.gui_console_visible")));
+                        ui.add_enabled_ui(edited.gui_console_enabled, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.label(t("ue4ss_settings.graphics_api"));
+                                egui::ComboBox::from_id_source("ue4ss_graphics_api")
+                                    .selected_text(edited.graphics_api.label())
+                                    .show_ui(ui, |ui| {
+                                        for api in core::GraphicsApi::ALL {
+                                            ui.selectable_value(&mut edited.graphics_api, api, api.label());
+                                        }
+                                    });
+                            });
+                        });
+                        ui.checkbox(&mut edited.hot_reload, t("ue4ss_settings.hot_reload"));
+                    });
+                    if &edited != settings {
+                        match core::write_ue4ss_settings(&self.win64_dir, &edited) {
+                            Ok(()) => self.ue4ss_settings = Some((self.win64_dir.clone(), Some(edited))),
+                            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to save UE4SS settings: {}\n", e)),
+                        }
+                    }
+                }
+            }
+            ui.add_space(16.0);
+            ui.group(|ui| {
+                ui.heading(t("mods.heading"));
+                ui.add_space(8.0);
+                let button_frame = |ui: &mut egui::Ui, text: &str| {
+                    ui.add_sized([
+                        220.0 * button_scale, 36.0 * button_scale
+                    ], egui::Button::new(egui::RichText::new(text).color(accent_text)).fill(accent_color))
+                };
+                if self.game_running {
+                    ui.label(egui::RichText::new(format!("⚠ {}", t("mods.game_running"))).color(egui::Color32::from_rgb(220, 80, 80)));
+                    ui.add_space(4.0);
                 }
+                ui.add_enabled_ui(!self.game_running, |ui| {
+                    if let Some(task) = &self.ue4ss_task {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            match task.scanned {
+                                Some(scanned) => {
+                                    ui.label(t("mods.scanning_directory").replace("{count}", &scanned.to_string()));
+                                }
+                                None => {
+                                    ui.label(t("mods.downloading_ue4ss"));
+                                    if ui.button(t("common.cancel")).clicked() {
+                                        task.cancel.store(true, Ordering::Relaxed);
+                                    }
+                                }
+                            }
+                        });
+                    } else if button_frame(ui, &t("mods.install_ue4ss")).on_hover_text(shortcut_hint("Ctrl+U")).clicked() {
+                        self.queue_task(TaskKind::InstallUe4ss);
+                    }
+                    ui.label(
+                        egui::RichText::new(t("mods.ue4ss_pinned").replace("{version}", core::pinned_ue4ss_version()))
+                            .small()
+                            .color(egui::Color32::GRAY),
+                    );
+                    if let Some(installed) = self.mod_summary.as_ref().and_then(|summary| summary.ue4ss_version.as_deref()) {
+                        ui.label(
+                            egui::RichText::new(t("mods.ue4ss_installed_version").replace("{version}", installed))
+                                .small()
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                    ui.add_space(4.0);
+                    match &self.download_task {
+                        Some(task) if task.kind == TaskKind::PreviewUe4ss => {
+                            ui.horizontal(|ui| {
+                                ui.spinner();
+                                ui.label(self.task_progress(&task.kind));
+                                if ui.button(t("common.cancel")).clicked() {
+                                    task.cancel.store(true, Ordering::Relaxed);
+                                }
+                            });
+                        }
+                        _ => {
+                            if ui.add_sized([220.0 * button_scale, 28.0 * button_scale], egui::Button::new(t("mods.preview_ue4ss"))).clicked() {
+                                self.queue_task(TaskKind::PreviewUe4ss);
+                            }
+                        }
+                    }
+                    ui.add_space(8.0);
+                    if button_frame(ui, &t("mods.install_mod")).on_hover_text(shortcut_hint("Ctrl+I")).clicked() {
+                        self.install_mod_from_file();
+                    }
+                    ui.add_space(4.0);
+                    ui.label(t("mods.install_url"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::TextEdit::singleline(&mut self.install_url).desired_width(150.0).hint_text("https://..."));
+                        match &self.download_task {
+                            Some(task) if matches!(task.kind, TaskKind::InstallFromUrl { .. }) => {
+                                ui.spinner();
+                                ui.label(self.task_progress(&task.kind));
+                                if ui.button(t("common.cancel")).clicked() {
+                                    task.cancel.store(true, Ordering::Relaxed);
+                                }
+                            }
+                            _ => {
+                                if ui.button(t("mods.install_url_button")).clicked() {
+                                    let url = self.install_url.clone();
+                                    self.install_from_url(&url, false);
+                                }
+                            }
+                        }
+                    });
+                    if ui.button(t("mods.browse")).on_hover_text(t("mods.browse_hint")).clicked() {
+                        self.open_mod_browser();
+                    }
+                    ui.checkbox(&mut self.clean_install, t("mods.clean_reinstall"))
+                        .on_hover_text(t("mods.clean_reinstall_hint"));
+                    ui.add_space(4.0);
+                    if let Some(task) = &self.update_task {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(format!("{} ({}/{})", t("mods.updating_all"), task.done, task.total));
+                            ui.label(
+                                t("mods.downloads_progress")
+                                    .replace("{active}", &task.downloads.active.load(Ordering::Relaxed).to_string())
+                                    .replace("{size}", &core::format_size(task.downloads.bytes.load(Ordering::Relaxed))),
+                            );
+                        });
+                    } else if ui
+                        .add_sized([220.0 * button_scale, 28.0 * button_scale], egui::Button::new(t("mods.update_all")))
+                        .on_hover_text(t("mods.update_all_hint"))
+                        .clicked()
+                    {
+                        self.queue_task(TaskKind::UpdateAll);
+                    }
+                });
                 ui.add_space(8.0);
-                if button_frame(ui, "Open Mods Folder").clicked() {
+                if button_frame(ui, &t("mods.open_folder")).clicked() {
                     if self.win64_dir.is_empty() {
                         self.debug_output.clear();
                         self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
                     } else {
-                        let mods_path = std::path::Path::new(&self.win64_dir).join("Mods");
+                        let mods_path = core::mods_dir(&self.win64_dir);
                         if !mods_path.exists() {
                             if let Err(e) = std::fs::create_dir_all(&mods_path) {
                                 self.debug_output.clear();
@@ -284,43 +1857,470 @@ impl eframe::App for GuiApp {
                                 return;
                             }
                         }
-                        #[cfg(windows)]
-                        {
-                            let _ = std::process::Command::new("explorer").arg(mods_path).spawn();
-                        }
-                        #[cfg(target_os = "macos")]
-                        {
-                            let _ = std::process::Command::new("open").arg(mods_path).spawn();
-                        }
-                        #[cfg(target_os = "linux")]
-                        {
-                            let _ = std::process::Command::new("xdg-open").arg(mods_path).spawn();
+                        open_in_file_manager(&mods_path);
+                    }
+                }
+                ui.add_space(8.0);
+                if button_frame(ui, &t("mods.validate_load_order")).clicked() {
+                    self.validate_load_order();
+                }
+                ui.add_space(8.0);
+                ui.add_enabled_ui(!self.game_running, |ui| {
+                    if button_frame(ui, &t("mods.apply_template")).on_hover_text(t("mods.apply_template_hint")).clicked() {
+                        self.apply_load_order_template();
+                    }
+                    ui.add_space(8.0);
+                    if button_frame(ui, &t("mods.restore_backup")).clicked() {
+                        if self.win64_dir.is_empty() {
+                            self.debug_output.clear();
+                            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+                        } else {
+                            self.backup_browser = Some(BackupBrowser {
+                                backups: core::list_backups(&self.win64_dir),
+                                selected: None,
+                                contents: Vec::new(),
+                                picked: HashSet::new(),
+                            });
                         }
                     }
+                });
+                ui.add_space(8.0);
+                if button_frame(ui, &t("mods.export_all")).on_hover_text(t("mods.export_all_hint")).clicked() {
+                    self.export_all_mods();
                 }
+                ui.add_space(4.0);
+                ui.add_enabled_ui(!self.game_running, |ui| {
+                    if ui
+                        .add_sized([220.0 * button_scale, 28.0 * button_scale], egui::Button::new(t("mods.import_all")))
+                        .on_hover_text(t("mods.import_all_hint"))
+                        .clicked()
+                    {
+                        self.import_all_mods();
+                    }
+                    ui.add_space(8.0);
+                    let reset_button = egui::Button::new(egui::RichText::new(t("mods.reset_vanilla")).color(egui::Color32::WHITE))
+                        .fill(egui::Color32::from_rgb(180, 40, 40));
+                    if ui.add_sized([220.0 * button_scale, 36.0 * button_scale], reset_button).clicked() {
+                        if self.win64_dir.is_empty() {
+                            self.debug_output.clear();
+                            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+                        } else {
+                            self.confirm_reset = true;
+                        }
+                    }
+                });
             });
             ui.add_space(16.0);
         });
 
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.push_id("installed_mods_section", |ui| {
-                ui.heading("Installed Mods Folder List:");
+                ui.horizontal(|ui| {
+                    ui.heading(t("installed.heading"));
+                    ui.label(t("installed.sort"));
+                    let previous_sort = self.config.mod_sort;
+                    egui::ComboBox::from_id_source("mod_sort_select")
+                        .selected_text(self.config.mod_sort.label())
+                        .show_ui(ui, |ui| {
+                            for sort in core::ModSort::ALL {
+                                ui.selectable_value(&mut self.config.mod_sort, sort, sort.label());
+                            }
+                        });
+                    if self.config.mod_sort != previous_sort {
+                        config::save_config(&self.config);
+                        self.selected_mod = None;
+                        self.update_mod_list();
+                    }
+                    if !self.checked_mods.is_empty() {
+                        let label = t("installed.uninstall_selected").replace("{count}", &self.checked_mods.len().to_string());
+                        if ui.add_enabled(!self.game_running, egui::Button::new(label)).clicked() {
+                            let mut names: Vec<String> = self.checked_mods.iter().cloned().collect();
+                            names.sort_by_key(|name| name.to_lowercase());
+                            self.confirm_uninstall = Some(names);
+                        }
+                    }
+                    if ui.button(t("installed.refresh")).on_hover_text(shortcut_hint("F5")).clicked() {
+                        self.refresh_mod_list();
+                    }
+                    if ui
+                        .checkbox(&mut self.config.auto_refresh, t("installed.auto_refresh"))
+                        .on_hover_text(t("installed.auto_refresh_hint"))
+                        .changed()
+                    {
+                        config::save_config(&self.config);
+                    }
+                });
+                if let Some(solo) = self.solo_mod.clone() {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(egui::Color32::from_rgb(220, 170, 60), t("installed.solo_active").replace("{name}", &solo));
+                        if ui
+                            .add_enabled(!self.game_running, egui::Button::new(t("installed.solo_restore")))
+                            .on_hover_text(t("installed.solo_restore_hint"))
+                            .on_disabled_hover_text(t("mods.game_running"))
+                            .clicked()
+                        {
+                            self.restore_solo();
+                        }
+                    });
+                }
+                if let Some(summary) = &self.mod_summary {
+                    let ue4ss = summary.ue4ss_version.clone().unwrap_or_else(|| t("installed.ue4ss_missing"));
+                    ui.label(
+                        egui::RichText::new(
+                            t("installed.summary")
+                                .replace("{total}", &summary.total.to_string())
+                                .replace("{enabled}", &summary.enabled.to_string())
+                                .replace("{disabled}", &summary.disabled().to_string())
+                                .replace("{size}", &core::format_size(summary.size))
+                                .replace("{ue4ss}", &ue4ss),
+                        )
+                        .color(egui::Color32::GRAY),
+                    );
+                }
+                if !self.installed_mods.is_empty() && !self.ue4ss_installed {
+                    egui::Frame::group(ui.style())
+                        .fill(egui::Color32::from_rgb(120, 30, 30))
+                        .inner_margin(8.0)
+                        .show(ui, |ui| {
+                            ui.horizontal_wrapped(|ui| {
+                                ui.label(egui::RichText::new(format!("⚠ {}", t("installed.ue4ss_missing_banner"))).color(egui::Color32::WHITE).strong());
+                                if self.ue4ss_task.is_some() {
+                                    ui.spinner();
+                                } else if ui.add_enabled(!self.game_running, egui::Button::new(t("mods.install_ue4ss"))).clicked() {
+                                    self.queue_task(TaskKind::InstallUe4ss);
+                                }
+                            });
+                        });
+                }
+                for warning in &self.dependency_warnings {
+                    ui.label(egui::RichText::new(format!("⚠ {}", warning)).color(egui::Color32::from_rgb(230, 160, 60)));
+                }
                 if self.installed_mods.is_empty() {
-                    ui.label("(No mods detected)");
+                    self.onboarding_card(ui, accent_color, accent_text, button_scale);
                 } else {
                     egui::ScrollArea::vertical()
                         .id_source("installed_mods_scroll")
                         .max_height(200.0)
                         .show(ui, |ui| {
-                            for m in &self.installed_mods {
-                                ui.label(m);
+                            let mods_dir = core::mods_dir(&self.win64_dir);
+                            let mut save_url_for = None;
+                            let mut save_note_for = None;
+                            let mut redownload = None;
+                            let mut clicked_mod = None;
+                            let mut menu_action = None;
+                            let mut edit_notes_for = None;
+                            let mut show_readme_for = None;
+                            for (index, m) in self.installed_mods.iter().enumerate() {
+                                let selected = self.selected_mod == Some(index);
+                                let kind = *self
+                                    .mod_kinds
+                                    .entry(m.clone())
+                                    .or_insert_with(|| core::classify_mod(&mods_dir.join(m)));
+                                let managed = self.managed_mods.contains(m);
+                                let title = mod_title(ui, m, kind, managed, selected.then_some(accent_color));
+                                let response = egui::CollapsingHeader::new(title).id_source(m).show(ui, |ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.label(t("installed.source_url"));
+                                        let url = self.source_urls.entry(m.clone()).or_insert_with(|| {
+                                            core::read_manifest(&mods_dir.join(m))
+                                                .and_then(|manifest| manifest.source_url)
+                                                .unwrap_or_default()
+                                        });
+                                        if ui.text_edit_singleline(url).lost_focus() {
+                                            save_url_for = Some(m.clone());
+                                        }
+                                    });
+                                    ui.horizontal(|ui| {
+                                        if ui
+                                            .add_enabled(!self.game_running, egui::Button::new(t("installed.update_from_source")))
+                                            .on_disabled_hover_text(t("mods.game_running"))
+                                            .clicked()
+                                        {
+                                            redownload = Some(m.clone());
+                                        }
+                                        if ui.button(t("installed.info")).on_hover_text(t("installed.info_hint")).clicked() {
+                                            show_readme_for = Some(m.clone());
+                                        }
+                                    });
+                                    ui.label(t("installed.notes"));
+                                    let note = self
+                                        .notes
+                                        .entry(m.clone())
+                                        .or_insert_with(|| core::get_note(&self.win64_dir, m));
+                                    let response = ui.add(egui::TextEdit::multiline(note).desired_rows(3).desired_width(f32::INFINITY));
+                                    if self.focus_note_for.as_deref() == Some(m.as_str()) {
+                                        response.request_focus();
+                                        self.focus_note_for = None;
+                                    }
+                                    if response.lost_focus() {
+                                        save_note_for = Some(m.clone());
+                                    }
+                                });
+                                if let Some(warning) = self.compat_warnings.get(m) {
+                                    ui.label(egui::RichText::new(format!("⚠ {}", warning)).color(egui::Color32::from_rgb(220, 80, 80)))
+                                        .on_hover_text(t("installed.compat_hint"));
+                                }
+                                if let Some(mismatch) = self.version_mismatches.get(m) {
+                                    ui.label(egui::RichText::new(format!("⚠ {}", mismatch)).color(egui::Color32::from_rgb(230, 160, 60)))
+                                        .on_hover_text(t("installed.version_mismatch_hint"));
+                                }
+                                let header = response.header_response.on_hover_text(if managed {
+                                    t("installed.managed_hint")
+                                } else {
+                                    t("installed.manual_hint")
+                                });
+                                if header.clicked() {
+                                    clicked_mod = Some(index);
+                                }
+                                if selected && selection_moved {
+                                    header.scroll_to_me(None);
+                                }
+                                // Star toggle drawn over the right end of the header row
+                                let favorite = self.favorites.contains(m);
+                                let star_rect = egui::Rect::from_min_max(
+                                    egui::pos2(header.rect.right() - 24.0, header.rect.top()),
+                                    header.rect.right_bottom(),
+                                );
+                                let star = egui::Button::new(
+                                    egui::RichText::new(if favorite { "★" } else { "☆" })
+                                        .color(if favorite { egui::Color32::from_rgb(240, 190, 40) } else { egui::Color32::GRAY }),
+                                )
+                                .frame(false);
+                                if ui
+                                    .put(star_rect, star)
+                                    .on_hover_text(if favorite { t("mod_menu.unpin") } else { t("mod_menu.pin") })
+                                    .clicked()
+                                {
+                                    menu_action = Some(ModMenuAction::SetFavorite(m.clone(), !favorite));
+                                }
+                                if managed {
+                                    let check_rect = egui::Rect::from_min_max(
+                                        egui::pos2(header.rect.right() - 52.0, header.rect.top()),
+                                        egui::pos2(header.rect.right() - 26.0, header.rect.bottom()),
+                                    );
+                                    let mut checked = self.checked_mods.contains(m);
+                                    if ui.put(check_rect, egui::Checkbox::without_text(&mut checked)).on_hover_text(t("installed.check_hint")).changed() {
+                                        if checked {
+                                            self.checked_mods.insert(m.clone());
+                                        } else {
+                                            self.checked_mods.remove(m);
+                                        }
+                                    }
+                                }
+                                if let Some(&updated) = self.updated_at.get(m) {
+                                    // Left of the checkbox and star, like a right-aligned column
+                                    let right = header.rect.right() - if managed { 56.0 } else { 28.0 };
+                                    let time_rect = egui::Rect::from_min_max(
+                                        egui::pos2(right - 110.0, header.rect.top()),
+                                        egui::pos2(right, header.rect.bottom()),
+                                    );
+                                    let label = egui::Label::new(egui::RichText::new(format_ago(updated)).small().color(egui::Color32::GRAY));
+                                    ui.put(time_rect, label)
+                                        .on_hover_text(format!("{}: {}", t("installed.updated"), humantime::format_rfc3339_seconds(updated)));
+                                }
+                                let header_id = header.id;
+                                header.context_menu(|ui| {
+                                    if ui.button(t("mod_menu.copy_name")).clicked() {
+                                        ui.output_mut(|o| o.copied_text = m.clone());
+                                        ui.close_menu();
+                                    }
+                                    if ui.button(t("mod_menu.open_folder")).clicked() {
+                                        open_in_file_manager(&mods_dir.join(m));
+                                        ui.close_menu();
+                                    }
+                                    if ui.button(t("mod_menu.show_files")).on_hover_text(t("mod_menu.show_files_hint")).clicked() {
+                                        menu_action = Some(ModMenuAction::ShowFiles(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    let script = core::mod_main_script(&mods_dir.join(m));
+                                    if ui
+                                        .add_enabled(script.is_some(), egui::Button::new(t("mod_menu.edit_script")))
+                                        .on_hover_text(t("mod_menu.edit_script_hint"))
+                                        .on_disabled_hover_text(t("mod_menu.no_script"))
+                                        .clicked()
+                                    {
+                                        if let Some(script) = &script {
+                                            open_in_file_manager(script);
+                                        }
+                                        ui.close_menu();
+                                    }
+                                    if ui
+                                        .add_enabled(!self.game_running, egui::Button::new(t("mod_menu.duplicate")))
+                                        .on_hover_text(t("mod_menu.duplicate_hint"))
+                                        .on_disabled_hover_text(t("mods.game_running"))
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::Duplicate(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    let enabled = self.enabled_mods.contains(m);
+                                    let toggle = if enabled { t("mod_menu.disable") } else { t("mod_menu.enable") };
+                                    if ui.add_enabled(!self.game_running, egui::Button::new(toggle)).on_disabled_hover_text(t("mods.game_running")).clicked() {
+                                        menu_action = Some(ModMenuAction::SetEnabled(m.clone(), !enabled));
+                                        ui.close_menu();
+                                    }
+                                    let pin = if favorite { t("mod_menu.unpin") } else { t("mod_menu.pin") };
+                                    if ui.button(pin).clicked() {
+                                        menu_action = Some(ModMenuAction::SetFavorite(m.clone(), !favorite));
+                                        ui.close_menu();
+                                    }
+                                    let can_reinstall = core::reinstall_source(m, &self.win64_dir).is_some();
+                                    if ui
+                                        .add_enabled(can_reinstall && !self.game_running, egui::Button::new(t("mod_menu.reinstall")))
+                                        .on_hover_text(t("mod_menu.reinstall_hint"))
+                                        .on_disabled_hover_text(if self.game_running {
+                                            t("mods.game_running")
+                                        } else {
+                                            t("mod_menu.reinstall_unavailable")
+                                        })
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::Reinstall(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    if ui
+                                        .add_enabled(managed, egui::Button::new(t("mod_menu.verify")))
+                                        .on_hover_text(t("mod_menu.verify_hint"))
+                                        .on_disabled_hover_text(t("mod_menu.verify_manual"))
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::Verify(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    if ui
+                                        .add_enabled(can_reinstall, egui::Button::new(t("mod_menu.compare_source")))
+                                        .on_hover_text(t("mod_menu.compare_source_hint"))
+                                        .on_disabled_hover_text(t("mod_menu.reinstall_unavailable"))
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::CompareToSource(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    if ui
+                                        .add_enabled(!self.game_running, egui::Button::new(t("mod_menu.solo")))
+                                        .on_hover_text(t("mod_menu.solo_hint"))
+                                        .on_disabled_hover_text(t("mods.game_running"))
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::Solo(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    if ui.button(t("mod_menu.edit_notes")).clicked() {
+                                        edit_notes_for = Some((header_id, m.clone()));
+                                        ui.close_menu();
+                                    }
+                                    ui.separator();
+                                    let uninstall = egui::Button::new(egui::RichText::new(t("mod_menu.uninstall")).color(egui::Color32::from_rgb(200, 60, 60)));
+                                    if ui
+                                        .add_enabled(managed && !self.game_running, uninstall)
+                                        .on_disabled_hover_text(if self.game_running {
+                                            t("mods.game_running")
+                                        } else {
+                                            t("mod_menu.uninstall_manual")
+                                        })
+                                        .clicked()
+                                    {
+                                        menu_action = Some(ModMenuAction::Uninstall(m.clone()));
+                                        ui.close_menu();
+                                    }
+                                });
+                            }
+                            if clicked_mod.is_some() {
+                                self.selected_mod = clicked_mod;
+                            }
+                            if let Some(name) = show_readme_for {
+                                let readme = core::read_mod_readme(&mods_dir.join(&name));
+                                let changelog = core::read_mod_changelog(&mods_dir.join(&name));
+                                self.readme_view = Some((name, readme, changelog));
+                            }
+                            if let Some((id, name)) = edit_notes_for {
+                                let mut state = egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false);
+                                state.set_open(true);
+                                state.store(ui.ctx());
+                                self.focus_note_for = Some(name);
+                            }
+                            match menu_action {
+                                Some(ModMenuAction::SetEnabled(name, enabled)) => self.set_mod_enabled(&name, enabled),
+                                Some(ModMenuAction::SetFavorite(name, favorite)) => {
+                                    match core::set_favorite(&self.win64_dir, &name, favorite) {
+                                        Ok(()) => self.update_mod_list(),
+                                        Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to save favorites: {}\n", e)),
+                                    }
+                                }
+                                Some(ModMenuAction::Uninstall(name)) => self.confirm_uninstall = Some(vec![name]),
+                                Some(ModMenuAction::Reinstall(name)) => self.reinstall_mod(&name),
+                                Some(ModMenuAction::Verify(name)) => {
+                                    let result = core::verify_mod_files(&mods_dir.join(&name)).map_err(|e| e.to_string());
+                                    self.integrity_report = Some((name, result));
+                                }
+                                Some(ModMenuAction::CompareToSource(name)) => {
+                                    let result = match core::reinstall_source(&name, &self.win64_dir) {
+                                        Some(archive) => core::compare_install_to_archive(&name, &archive.display().to_string(), &self.win64_dir)
+                                            .map_err(|e| e.to_string()),
+                                        None => Err(t("mod_menu.reinstall_unavailable")),
+                                    };
+                                    self.source_comparison = Some((name, result));
+                                }
+                                Some(ModMenuAction::ShowFiles(name)) => {
+                                    let files = core::list_mod_files(&mods_dir.join(&name));
+                                    self.file_view = Some((name, files));
+                                }
+                                Some(ModMenuAction::Duplicate(name)) => {
+                                    let new_name = format!("{}_copy", name);
+                                    self.clone_prompt = Some((name, new_name));
+                                }
+                                Some(ModMenuAction::Solo(name)) => self.start_solo(&name),
+                                None => {}
+                            }
+                            if let Some(name) = save_note_for {
+                                let note = self.notes.get(&name).cloned().unwrap_or_default();
+                                if let Err(e) = core::set_note(&self.win64_dir, &name, &note) {
+                                    self.debug_output.push_str(&format!("[ERROR] Failed to save notes for '{}': {}\n", name, e));
+                                }
+                            }
+                            if let Some(name) = save_url_for {
+                                let url = self.source_urls.get(&name).cloned().unwrap_or_default();
+                                if let Err(e) = core::set_source_url(&mods_dir.join(&name), &url) {
+                                    self.debug_output.push_str(&format!("[ERROR] Failed to save source URL for '{}': {}\n", name, e));
+                                }
+                            }
+                            if let Some(name) = redownload {
+                                self.queue_task(TaskKind::UpdateFromSource(name));
                             }
                         });
                 }
             });
             ui.separator();
+            ui.push_id("scanned_files_section", |ui| {
+                ui.heading(t("scanned.heading"));
+                ui.horizontal(|ui| {
+                    ui.label(t("scanned.filter"));
+                    ui.text_edit_singleline(&mut self.scanned_filter);
+                });
+                let filter = self.scanned_filter.to_lowercase();
+                let matches: Vec<&String> = self
+                    .scanned_files
+                    .iter()
+                    .filter(|path| path.to_lowercase().contains(&filter))
+                    .collect();
+                ui.label(egui::RichText::new(format!("{} of {} entries", matches.len(), self.scanned_files.len())).color(egui::Color32::GRAY));
+                let row_height = ui.text_style_height(&egui::TextStyle::Body);
+                egui::ScrollArea::vertical()
+                    .id_source("scanned_files_scroll")
+                    .max_height(160.0)
+                    .show_rows(ui, row_height, matches.len(), |ui, rows| {
+                        for path in &matches[rows] {
+                            ui.label(path.as_str());
+                        }
+                    });
+            });
+            if !self.tasks.is_empty() {
+                ui.separator();
+                self.tasks_ui(ui);
+            }
+            ui.separator();
             ui.push_id("debug_output_section", |ui| {
-                ui.heading("Debug Output:");
+                ui.heading(t("debug.heading"));
                 egui::ScrollArea::vertical()
                     .id_source("debug_output_scroll")
                     .max_height(120.0)
@@ -329,22 +2329,1951 @@ impl eframe::App for GuiApp {
                     });
             });
         });
-    }
-}
 
-impl GuiApp {
-    fn update_mod_list(&mut self) {
-        if self.win64_dir.is_empty() {
-            self.installed_mods.clear();
-            return;
+        if let Some(browser) = &mut self.mod_browser {
+            let mut open = true;
+            let mut install = None;
+            let mut refresh = false;
+            egui::Window::new(t("browse.title"))
+                .open(&mut open)
+                .default_width(560.0)
+                .show(ctx, |ui| match &browser.mods {
+                    None => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(t("browse.loading"));
+                        });
+                    }
+                    Some(Err(e)) => {
+                        ui.colored_label(egui::Color32::from_rgb(220, 80, 80), e.as_str());
+                        if ui.button(t("browse.retry")).clicked() {
+                            refresh = true;
+                        }
+                    }
+                    Some(Ok(mods)) => {
+                        ui.horizontal(|ui| {
+                            ui.add(egui::TextEdit::singleline(&mut browser.filter).hint_text(t("browse.filter")));
+                            if ui.button(t("browse.refresh")).clicked() {
+                                refresh = true;
+                            }
+                        });
+                        ui.separator();
+                        let filter = browser.filter.to_lowercase();
+                        egui::ScrollArea::vertical().max_height(420.0).show(ui, |ui| {
+                            for m in mods.iter().filter(|m| {
+                                m.name.to_lowercase().contains(&filter) || m.description.to_lowercase().contains(&filter)
+                            }) {
+                                ui.horizontal(|ui| {
+                                    let installed = self.installed_mods.iter().any(|name| name.eq_ignore_ascii_case(&m.name));
+                                    let label = if installed { t("browse.reinstall") } else { t("browse.install") };
+                                    if ui.add_enabled(!self.game_running, egui::Button::new(label)).on_hover_text(m.url.as_str()).clicked() {
+                                        install = Some(m.url.clone());
+                                    }
+                                    match &m.version {
+                                        Some(version) => ui.strong(format!("{} {}", m.name, version)),
+                                        None => ui.strong(&m.name),
+                                    };
+                                    if installed {
+                                        ui.colored_label(egui::Color32::from_rgb(80, 170, 90), t("browse.installed"));
+                                    }
+                                });
+                                if !m.description.is_empty() {
+                                    ui.label(egui::RichText::new(&m.description).color(egui::Color32::GRAY));
+                                }
+                                // Let users of an older copy see what they'd get before reinstalling
+                                if let Some(changelog) = &m.changelog {
+                                    if self.installed_mods.iter().any(|name| name.eq_ignore_ascii_case(&m.name)) {
+                                        egui::CollapsingHeader::new(t("browse.whats_new")).id_source(("changelog", &m.name)).show(ui, |ui| {
+                                            readme_ui(ui, changelog);
+                                        });
+                                    }
+                                }
+                                ui.add_space(4.0);
+                            }
+                        });
+                    }
+                });
+            if !open {
+                self.mod_browser = None;
+            } else if refresh {
+                self.open_mod_browser();
+            }
+            if let Some(url) = install {
+                self.install_from_url(&url, false);
+            }
+        }
+
+        if let Some((name, result)) = &self.integrity_report {
+            let mut open = true;
+            egui::Window::new(format!("{} — {}", name, t("verify.title")))
+                .open(&mut open)
+                .default_width(480.0)
+                .show(ctx, |ui| match result {
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(220, 170, 60), e.as_str());
+                    }
+                    Ok(issues) if issues.is_empty() => {
+                        ui.colored_label(egui::Color32::from_rgb(80, 170, 90), t("verify.ok"));
+                    }
+                    Ok(issues) => {
+                        ui.label(t("verify.issues"));
+                        egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                            for issue in issues {
+                                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {}", issue));
+                            }
+                        });
+                    }
+                });
+            if !open {
+                self.integrity_report = None;
+            }
+        }
+
+        if let Some((name, files)) = &self.file_view {
+            let mut open = true;
+            egui::Window::new(format!("{} — {}", name, t("files.title")))
+                .open(&mut open)
+                .default_width(520.0)
+                .show(ctx, |ui| {
+                    if files.is_empty() {
+                        ui.label(t("files.empty"));
+                        return;
+                    }
+                    let total: u64 = files.iter().filter_map(|file| file.size).sum();
+                    ui.label(
+                        t("files.summary")
+                            .replace("{count}", &files.len().to_string())
+                            .replace("{size}", &core::format_size(total)),
+                    );
+                    // Without a manifest there's no telling which files the mod came with
+                    let has_manifest = files.iter().any(|file| file.in_manifest);
+                    egui::ScrollArea::vertical().max_height(400.0).show(ui, |ui| {
+                        file_tree_ui(ui, files, "", has_manifest);
+                    });
+                });
+            if !open {
+                self.file_view = None;
+            }
+        }
+
+        if let Some((name, result)) = &self.source_comparison {
+            let mut open = true;
+            egui::Window::new(format!("{} — {}", name, t("compare_source.title")))
+                .open(&mut open)
+                .default_width(480.0)
+                .show(ctx, |ui| match result {
+                    Err(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(220, 170, 60), e.as_str());
+                    }
+                    Ok(report) if report.is_clean() => {
+                        ui.colored_label(
+                            egui::Color32::from_rgb(80, 170, 90),
+                            t("compare_source.ok").replace("{count}", &report.matching.to_string()),
+                        );
+                    }
+                    Ok(report) => {
+                        ui.label(t("compare_source.issues"));
+                        egui::ScrollArea::vertical().max_height(320.0).show(ui, |ui| {
+                            for file in &report.missing {
+                                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {}", t("compare_source.missing").replace("{file}", file)));
+                            }
+                            for file in &report.different {
+                                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {}", t("compare_source.different").replace("{file}", file)));
+                            }
+                        });
+                    }
+                });
+            if !open {
+                self.source_comparison = None;
+            }
+        }
+
+        if let Some((file_name, diff)) = &self.modlist_diff {
+            let mut open = true;
+            egui::Window::new(t("modlist_diff.title"))
+                .open(&mut open)
+                .default_width(480.0)
+                .show(ctx, |ui| {
+                    ui.label(egui::RichText::new(file_name).color(egui::Color32::GRAY));
+                    ui.separator();
+                    if diff.is_empty() {
+                        ui.label(t("modlist_diff.identical"));
+                    }
+                    egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                        if diff.ue4ss_differs {
+                            ui.colored_label(egui::Color32::from_rgb(220, 170, 60), t("modlist_diff.ue4ss_differs"));
+                        }
+                        let sections = [
+                            (t("modlist_diff.only_here"), &diff.only_in_a, egui::Color32::from_rgb(80, 170, 90)),
+                            (t("modlist_diff.only_there"), &diff.only_in_b, egui::Color32::from_rgb(220, 80, 80)),
+                            (t("modlist_diff.version_differs"), &diff.version_differs, egui::Color32::from_rgb(220, 170, 60)),
+                        ];
+                        for (heading, names, color) in sections {
+                            if names.is_empty() {
+                                continue;
+                            }
+                            ui.strong(heading);
+                            for name in names {
+                                ui.colored_label(color, name);
+                            }
+                            ui.add_space(4.0);
+                        }
+                        if !diff.enabled_differs.is_empty() {
+                            ui.strong(t("modlist_diff.enabled_differs"));
+                            for (name, enabled_here) in &diff.enabled_differs {
+                                let state = if *enabled_here { t("modlist_diff.enabled_here") } else { t("modlist_diff.disabled_here") };
+                                ui.label(format!("{} ({})", name, state));
+                            }
+                        }
+                    });
+                });
+            if !open {
+                self.modlist_diff = None;
+            }
+        }
+
+        if let Some((name, readme, changelog)) = &self.readme_view {
+            let mut open = true;
+            egui::Window::new(format!("{} — {}", name, t("readme.title")))
+                .open(&mut open)
+                .default_width(560.0)
+                .show(ctx, |ui| {
+                    match readme {
+                        Some(text) => {
+                            egui::ScrollArea::vertical().id_source("readme").max_height(420.0).show(ui, |ui| readme_ui(ui, text));
+                        }
+                        None => {
+                            ui.label(t("readme.none"));
+                        }
+                    }
+                    if let Some(changelog) = changelog {
+                        ui.separator();
+                        egui::CollapsingHeader::new(t("readme.changelog")).default_open(readme.is_none()).show(ui, |ui| {
+                            egui::ScrollArea::vertical().id_source("changelog").max_height(240.0).show(ui, |ui| readme_ui(ui, changelog));
+                        });
+                    }
+                });
+            if !open {
+                self.readme_view = None;
+            }
+        }
+
+        if let Some(planned) = &self.ue4ss_preview {
+            let mut open = true;
+            egui::Window::new(t("preview.title"))
+                .open(&mut open)
+                .default_width(520.0)
+                .show(ctx, |ui| {
+                    ui.label(format!("{} {}", planned.len(), t("preview.files_into")));
+                    ui.label(egui::RichText::new(&self.win64_dir).color(egui::Color32::GRAY));
+                    ui.separator();
+                    egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                        for (path, exists) in planned {
+                            if *exists {
+                                ui.label(format!("{}  ({})", path, t("preview.replaces_existing")));
+                            } else {
+                                ui.label(path);
+                            }
+                        }
+                    });
+                });
+            if !open {
+                self.ue4ss_preview = None;
+            }
         }
-        match core::list_installed_mods(&self.win64_dir) {
-            Ok(mods) => self.installed_mods = mods,
+
+        if self.load_order_issues.is_some() {
+            let mut open = true;
+            let mut fix_confirmed = false;
+            egui::Window::new(t("load_order.title"))
+                .open(&mut open)
+                .default_width(520.0)
+                .show(ctx, |ui| {
+                    let issues = self.load_order_issues.as_deref().unwrap_or_default();
+                    if issues.is_empty() {
+                        ui.label(t("load_order.no_issues"));
+                        return;
+                    }
+                    egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                        for issue in issues {
+                            ui.label(egui::RichText::new(issue.to_string()).color(egui::Color32::from_rgb(230, 160, 60)));
+                        }
+                    });
+                    ui.separator();
+                    if self.confirm_load_order_fix {
+                        ui.label(t("load_order.fix_confirm"));
+                        ui.horizontal(|ui| {
+                            if ui.button(t("load_order.fix")).clicked() {
+                                fix_confirmed = true;
+                            }
+                            if ui.button(t("common.cancel")).clicked() {
+                                self.confirm_load_order_fix = false;
+                            }
+                        });
+                    } else if ui
+                        .add_enabled(!self.game_running, egui::Button::new(t("load_order.fix")))
+                        .on_disabled_hover_text(t("mods.game_running"))
+                        .clicked()
+                    {
+                        self.confirm_load_order_fix = true;
+                    }
+                });
+            if fix_confirmed {
+                self.fix_load_order();
+            }
+            if !open {
+                self.load_order_issues = None;
+                self.confirm_load_order_fix = false;
+            }
+        }
+
+        if let Some(browser) = &mut self.backup_browser {
+            let mut open = true;
+            let mut restore = false;
+            let mut clicked_backup = None;
+            egui::Window::new(t("backups.title"))
+                .collapsible(false)
+                .resizable(true)
+                .open(&mut open)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    if browser.backups.is_empty() {
+                        ui.label(t("backups.none"));
+                        return;
+                    }
+                    ui.columns(2, |columns| {
+                        egui::ScrollArea::vertical().id_source("backup_list").max_height(300.0).show(&mut columns[0], |ui| {
+                            for (i, backup) in browser.backups.iter().enumerate() {
+                                let name = backup.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+                                if ui.selectable_label(browser.selected == Some(i), name).clicked() {
+                                    clicked_backup = Some(i);
+                                }
+                            }
+                        });
+                        egui::ScrollArea::vertical().id_source("backup_contents").max_height(300.0).show(&mut columns[1], |ui| {
+                            if browser.selected.is_none() {
+                                ui.label(egui::RichText::new(t("backups.pick")).color(egui::Color32::GRAY));
+                            }
+                            for item in &browser.contents {
+                                let mut picked = browser.picked.contains(item);
+                                if ui.checkbox(&mut picked, item.as_str()).changed() {
+                                    if picked {
+                                        browser.picked.insert(item.clone());
+                                    } else {
+                                        browser.picked.remove(item);
+                                    }
+                                }
+                            }
+                        });
+                    });
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!browser.picked.is_empty(), egui::Button::new(t("backups.restore"))).clicked() {
+                            restore = true;
+                        }
+                        ui.label(egui::RichText::new(t("backups.restore_hint")).color(egui::Color32::GRAY));
+                    });
+                });
+            if let Some(i) = clicked_backup {
+                browser.selected = Some(i);
+                browser.picked.clear();
+                browser.contents = match core::list_backup_contents(&browser.backups[i]) {
+                    Ok(contents) => contents,
+                    Err(e) => {
+                        self.debug_output.push_str(&format!("[ERROR] Failed to read backup {}: {}\n", browser.backups[i].display(), e));
+                        Vec::new()
+                    }
+                };
+            }
+            if restore {
+                if let Some(backup) = browser.selected.map(|i| browser.backups[i].clone()) {
+                    let mut picked: Vec<String> = browser.picked.drain().collect();
+                    picked.sort();
+                    self.restore_from_backup(&backup, &picked);
+                }
+            } else if !open {
+                self.backup_browser = None;
+            }
+        }
+
+        if let Some(message) = self.preflight_error.clone() {
+            let mut open = true;
+            egui::Window::new(t("preflight.title"))
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(egui::RichText::new(format!("⚠ {}", message)).color(egui::Color32::from_rgb(220, 80, 80)));
+                    ui.add_space(4.0);
+                    ui.label(t("preflight.nothing_written"));
+                });
+            if !open {
+                self.preflight_error = None;
+            }
+        }
+
+        if self.confirm_exit {
+            let mut exit = false;
+            egui::Window::new(t("exit.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("exit.body"));
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("exit.when_finished")).on_hover_text(t("exit.when_finished_hint")).clicked() {
+                            exit = true;
+                        }
+                        if ui.button(t("exit.keep_running")).clicked() {
+                            self.confirm_exit = false;
+                        }
+                    });
+                });
+            if exit {
+                self.confirm_exit = false;
+                self.exit_when_finished();
+            }
+        }
+
+        if self.confirm_reset {
+            let mut confirmed = false;
+            egui::Window::new(t("reset.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("reset.body"));
+                    ui.add_space(8.0);
+                    ui.checkbox(&mut self.reset_logic_mods, t("reset.logic_mods"));
+                    ui.checkbox(&mut self.reset_remove_ue4ss, t("reset.remove_ue4ss"));
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("reset.confirm")).clicked() {
+                            confirmed = true;
+                        }
+                        if ui.button(t("common.cancel")).clicked() {
+                            self.confirm_reset = false;
+                        }
+                    });
+                });
+            if confirmed {
+                self.confirm_reset = false;
+                self.reset_to_vanilla();
+            }
+        }
+
+        if let Some(zip_paths) = self.pending_target_choice.clone() {
+            let mut chosen = None;
+            egui::Window::new(t("unknown_target.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("unknown_target.body"));
+                    for path in &zip_paths {
+                        let name = Path::new(path).file_name().and_then(|n| n.to_str()).unwrap_or(path);
+                        ui.label(format!("• {}", name));
+                    }
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button("Mods").clicked() {
+                            chosen = Some(core::InstallTarget::Mods);
+                        }
+                        if ui.button("LogicMods").clicked() {
+                            chosen = Some(core::InstallTarget::LogicMods);
+                        }
+                        if ui.button(t("common.cancel")).clicked() {
+                            self.pending_target_choice = None;
+                        }
+                    });
+                });
+            if let Some(target) = chosen {
+                self.pending_target_choice = None;
+                self.install_mod_zips(&zip_paths, Some(target));
+            }
+        }
+
+        if let Some(pending) = &mut self.pending_options {
+            let mut confirmed = false;
+            let mut cancelled = false;
+            egui::Window::new(t("options.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("options.body"));
+                    ui.add_space(4.0);
+                    egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                        for (path, options, choices) in &mut pending.archives {
+                            ui.strong(Path::new(path.as_str()).file_name().and_then(|n| n.to_str()).unwrap_or(path.as_str()));
+                            for (group, choice) in options.groups.iter().zip(choices.iter_mut()) {
+                                ui.label(&group.name);
+                                if !group.description.is_empty() {
+                                    ui.label(egui::RichText::new(&group.description).color(egui::Color32::GRAY));
+                                }
+                                for (index, option) in group.options.iter().enumerate() {
+                                    let radio = ui.radio_value(choice, index, &option.name);
+                                    if !option.description.is_empty() {
+                                        radio.on_hover_text(&option.description);
+                                    }
+                                }
+                                ui.add_space(4.0);
+                            }
+                        }
+                    });
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("common.cancel")).clicked() {
+                            cancelled = true;
+                        }
+                        if ui.button(t("options.confirm")).clicked() {
+                            confirmed = true;
+                        }
+                    });
+                });
+            if confirmed {
+                if let Some(pending) = self.pending_options.take() {
+                    for (path, _, choices) in pending.archives {
+                        self.install_choices.insert(path, choices);
+                    }
+                    self.install_mod_zips(&pending.zip_paths, pending.unknown_target);
+                }
+            } else if cancelled {
+                self.pending_options = None;
+                self.install_choices.clear();
+                self.debug_output.push_str("[INFO] Install cancelled.\n");
+            }
+        }
+
+        if let Some(pending) = &self.pending_risky {
+            let mut confirmed = false;
+            let mut cancelled = false;
+            egui::Window::new(egui::RichText::new(t("risky.title")).color(egui::Color32::from_rgb(220, 60, 60)))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(egui::RichText::new(t("risky.body")).strong());
+                    ui.add_space(4.0);
+                    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                        for (name, entries) in &pending.entries {
+                            ui.strong(name);
+                            for entry in entries {
+                                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("⚠ {}", entry));
+                            }
+                            ui.add_space(4.0);
+                        }
+                    });
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("common.cancel")).clicked() {
+                            cancelled = true;
+                        }
+                        let install = egui::Button::new(egui::RichText::new(t("risky.confirm")).color(egui::Color32::WHITE))
+                            .fill(egui::Color32::from_rgb(180, 40, 40));
+                        if ui.add(install).clicked() {
+                            confirmed = true;
+                        }
+                    });
+                });
+            if confirmed {
+                if let Some(pending) = self.pending_risky.take() {
+                    match pending.source {
+                        RiskySource::Zips(zip_paths, unknown_target) => self.confirm_overwrites(&zip_paths, unknown_target),
+                        RiskySource::Url(url) => self.install_from_url(&url, true),
+                    }
+                }
+            } else if cancelled {
+                self.pending_risky = None;
+                self.debug_output.push_str("[INFO] Install cancelled.\n");
+            }
+        }
+
+        if let Some(pending) = &self.pending_overwrite {
+            let mut confirmed = false;
+            let mut cancelled = false;
+            egui::Window::new(t("overwrite.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("overwrite.body"));
+                    ui.add_space(4.0);
+                    egui::ScrollArea::vertical().max_height(240.0).show(ui, |ui| {
+                        for (name, diff) in &pending.diffs {
+                            ui.strong(name);
+                            ui.label(diff.summary());
+                            for file in &diff.changed {
+                                ui.colored_label(egui::Color32::from_rgb(220, 170, 60), format!("~ {}", file));
+                            }
+                            for file in &diff.removed {
+                                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), format!("- {}", file));
+                            }
+                            ui.add_space(4.0);
+                        }
+                    });
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("overwrite.confirm")).clicked() {
+                            confirmed = true;
+                        }
+                        if ui.button(t("common.cancel")).clicked() {
+                            cancelled = true;
+                        }
+                    });
+                });
+            if confirmed {
+                if let Some(pending) = self.pending_overwrite.take() {
+                    self.run_install(&pending.zip_paths, pending.unknown_target);
+                }
+            } else if cancelled {
+                self.pending_overwrite = None;
+                self.debug_output.push_str("[INFO] Install cancelled.\n");
+            }
+        }
+
+        if let Some(names) = self.confirm_uninstall.clone() {
+            let mut confirmed = false;
+            egui::Window::new(t("uninstall.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    if let [name] = names.as_slice() {
+                        ui.label(t("uninstall.body").replace("{name}", name));
+                    } else {
+                        ui.label(t("uninstall.body_many").replace("{count}", &names.len().to_string()));
+                        egui::ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                            for name in &names {
+                                ui.label(format!("• {}", name));
+                            }
+                        });
+                    }
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("uninstall.confirm")).clicked() {
+                            confirmed = true;
+                        }
+                        if ui.button(t("common.cancel")).clicked() {
+                            self.confirm_uninstall = None;
+                        }
+                    });
+                });
+            if confirmed {
+                self.confirm_uninstall = None;
+                self.uninstall_mods(&names);
+            }
+        }
+
+        if let Some((name, mut new_name)) = self.clone_prompt.take() {
+            let mut open = true;
+            let mut confirmed = false;
+            let mut cancelled = false;
+            egui::Window::new(t("duplicate.title"))
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(t("duplicate.body").replace("{name}", &name));
+                    let response = ui.text_edit_singleline(&mut new_name);
+                    if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                        confirmed = true;
+                    }
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(!new_name.trim().is_empty(), egui::Button::new(t("duplicate.confirm"))).clicked() {
+                            confirmed = true;
+                        }
+                        if ui.button(t("common.cancel")).clicked() {
+                            cancelled = true;
+                        }
+                    });
+                });
+            if confirmed && !self.blocked_by_running_game() {
+                match core::clone_mod(&self.win64_dir, &name, &new_name) {
+                    Ok(()) => {
+                        self.debug_output.push_str(&format!(
+                            "[INFO] {}\n",
+                            t("duplicate.done").replace("{name}", &name).replace("{new_name}", new_name.trim())
+                        ));
+                        self.update_mod_list();
+                    }
+                    Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to duplicate '{}': {}\n", name, e)),
+                }
+            } else if open && !cancelled {
+                self.clone_prompt = Some((name, new_name));
+            }
+        }
+
+        if self.dir_change_prompt.is_some() && self.pending_selection() == (0, 0) {
+            // Everything ticked was missing from the new directory, so nothing is left to decide
+            self.dir_change_prompt = None;
+        }
+        if let Some(previous_dir) = self.dir_change_prompt.clone() {
+            let (checked, queued) = self.pending_selection();
+            let mut keep = false;
+            let mut discard = false;
+            let mut go_back = false;
+            egui::Window::new(t("dir_change.title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(
+                        t("dir_change.body")
+                            .replace("{checked}", &checked.to_string())
+                            .replace("{queued}", &queued.to_string())
+                            .replace("{dir}", &self.win64_dir),
+                    );
+                    ui.add_space(8.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(t("dir_change.discard")).on_hover_text(t("dir_change.discard_hint")).clicked() {
+                            discard = true;
+                        }
+                        if ui.button(t("dir_change.keep")).clicked() {
+                            keep = true;
+                        }
+                        if ui.button(t("dir_change.go_back")).on_hover_text(previous_dir.as_str()).clicked() {
+                            go_back = true;
+                        }
+                    });
+                });
+            if discard {
+                self.checked_mods.clear();
+                self.confirm_uninstall = None;
+                for task in self.tasks.iter_mut().filter(|task| matches!(task.status, TaskStatus::Queued)) {
+                    task.status = TaskStatus::Cancelled;
+                }
+                self.debug_output.push_str("[INFO] Cleared the selection and queued tasks for the previous game directory.\n");
+            } else if go_back {
+                self.win64_dir = previous_dir;
+                self.update_mod_list();
+            }
+            if keep || discard || go_back {
+                self.dir_change_prompt = None;
+            }
+        }
+    }
+}
+
+impl GuiApp {
+    /// Whether a download or install is running in the background
+    fn task_running(&self) -> bool {
+        self.ue4ss_task.is_some() || self.update_task.is_some() || self.download_task.is_some()
+    }
+
+    /// Remember the window geometry and save it to the cache when the window is closed.
+    /// Closing is held off with a confirmation while a download or install is running, since
+    /// quitting mid-install can leave a mod half-written.
+    /// On the first frame, re-center the window if it was restored onto a display that
+    /// is no longer the same (e.g. a monitor was unplugged) so it doesn't open off-screen.
+    fn track_window_geometry(&mut self, ctx: &egui::Context) {
+        let (inner, outer, monitor, close_requested) = ctx.input(|i| {
+            let v = i.viewport();
+            (v.inner_rect, v.outer_rect, v.monitor_size, v.close_requested())
+        });
+        let monitor_size = monitor.map(|m| [m.x, m.y]);
+        if !self.geometry_checked && monitor_size.is_some() {
+            self.geometry_checked = true;
+            if let Some(saved) = self.cache.window {
+                if saved.pos.is_some() && saved.monitor_size != monitor_size {
+                    if let Some(cmd) = egui::ViewportCommand::center_on_screen(ctx) {
+                        ctx.send_viewport_cmd(cmd);
+                    }
+                }
+            }
+        }
+        if let Some(inner) = inner {
+            self.cache.window = Some(WindowGeometry {
+                size: [inner.width(), inner.height()],
+                pos: outer.map(|o| [o.min.x, o.min.y]),
+                monitor_size,
+            });
+        }
+        if close_requested {
+            if self.task_running() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                if !self.exit_when_idle {
+                    self.confirm_exit = true;
+                }
+            } else {
+                self.cache.last_win64_dir = self.win64_dir.clone();
+                self.cache.last_debug_output = self.debug_output.clone();
+                save_cache(&self.cache);
+                log::logger().flush();
+            }
+        }
+    }
+
+    /// Point the Mods folder watcher at the current directory, recreating it when the
+    /// directory changes, and refresh the list if the folder changed since the last frame
+    fn sync_mods_watcher(&mut self, ctx: &egui::Context) {
+        let mods_path = if self.win64_dir.is_empty() { None } else { Some(core::mods_dir(&self.win64_dir)) };
+        let mods_path = mods_path.filter(|path| path.is_dir());
+        let current = self.mods_watcher.as_ref().map(|watcher| &watcher.path);
+        if current != mods_path.as_ref() {
+            self.mods_watcher = None;
+            if let Some(path) = mods_path {
+                match ModsWatcher::new(path, ctx) {
+                    Ok(watcher) => self.mods_watcher = Some(watcher),
+                    Err(e) => debug_println!(self, "[ERROR] Could not watch the Mods folder: {}\n", e),
+                }
+            }
+        }
+        let changed = match &self.mods_watcher {
+            Some(watcher) => watcher.events.try_iter().count() > 0,
+            None => false,
+        };
+        if changed {
+            self.update_mod_list();
+        }
+    }
+
+    /// Enable only `name` to test it on its own, remembering which mods were enabled before
+    fn start_solo(&mut self, name: &str) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        match core::solo_mod(&self.win64_dir, name) {
+            Ok(()) => self.debug_output.push_str(&format!("[INFO] Only '{}' is enabled now. Use Restore previous state to undo.\n", name)),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to solo '{}': {}\n", name, e)),
+        }
+        self.update_mod_list();
+    }
+
+    fn restore_solo(&mut self) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        match core::restore_solo_snapshot(&self.win64_dir) {
+            Ok(()) => self.debug_output.push_str("[INFO] Restored the mods enabled before solo mode.\n"),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to restore the previous mod state: {}\n", e)),
+        }
+        self.update_mod_list();
+    }
+
+    fn set_mod_enabled(&mut self, name: &str, enabled: bool) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        match core::set_mod_enabled(&self.win64_dir, name, enabled) {
+            Ok(()) => {
+                if enabled {
+                    self.enabled_mods.insert(name.to_string());
+                } else {
+                    self.enabled_mods.remove(name);
+                }
+                let state = if enabled { "enabled" } else { "disabled" };
+                self.debug_output.push_str(&format!("[INFO] Mod '{}' {}.\n", name, state));
+            }
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to update mods.txt for '{}': {}\n", name, e)),
+        }
+        self.update_mod_list();
+    }
+
+    /// Refresh `game_running` every few seconds, and keep repainting so the buttons re-enable
+    /// once the game is closed even if the window is idle
+    fn poll_game_running(&mut self, ctx: &egui::Context) {
+        if self.win64_dir.is_empty() {
+            self.game_running = false;
+            return;
+        }
+        let due = match self.game_checked_at {
+            Some(at) => at.elapsed() >= GAME_CHECK_INTERVAL,
+            None => true,
+        };
+        if due {
+            self.game_running = core::is_game_running(&self.win64_dir);
+            self.game_checked_at = Some(Instant::now());
+        }
+        ctx.request_repaint_after(GAME_CHECK_INTERVAL);
+    }
+
+    /// Shown instead of the empty mods list: walks a newcomer through picking the game folder,
+    /// installing UE4SS, and installing a first mod, in that order
+    fn onboarding_card(&mut self, ui: &mut egui::Ui, accent_color: egui::Color32, accent_text: egui::Color32, button_scale: f32) {
+        egui::Frame::group(ui.style()).inner_margin(16.0).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.strong(t("onboarding.heading"));
+            ui.add_space(4.0);
+            let primary = |ui: &mut egui::Ui, text: String, enabled: bool| {
+                ui.add_enabled(
+                    enabled,
+                    egui::Button::new(egui::RichText::new(text).color(accent_text))
+                        .fill(accent_color)
+                        .min_size(egui::vec2(260.0 * button_scale, 36.0 * button_scale)),
+                )
+            };
+            if self.win64_dir.is_empty() {
+                ui.label(t("onboarding.select_dir"));
+                ui.add_space(8.0);
+                if primary(ui, t("onboarding.select_dir_button"), true).clicked() {
+                    self.select_directory();
+                }
+                return;
+            }
+            let can_install = !self.game_running;
+            if !core::detect_ue4ss(&self.win64_dir) {
+                ui.label(t("onboarding.ue4ss_missing"));
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new(t("onboarding.step_ue4ss")).strong());
+                if self.ue4ss_task.is_some() {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(t("mods.downloading_ue4ss"));
+                    });
+                } else if primary(ui, t("mods.install_ue4ss"), can_install).clicked() {
+                    self.queue_task(TaskKind::InstallUe4ss);
+                }
+                ui.add_space(8.0);
+                ui.label(egui::RichText::new(t("onboarding.step_mod")).strong());
+                if ui.add_enabled(can_install, egui::Button::new(t("onboarding.first_mod"))).clicked() {
+                    self.install_mod_from_file();
+                }
+            } else {
+                ui.label(t("onboarding.ready"));
+                ui.add_space(8.0);
+                if primary(ui, t("onboarding.first_mod"), can_install).clicked() {
+                    self.install_mod_from_file();
+                }
+            }
+            if self.game_running {
+                ui.colored_label(egui::Color32::from_rgb(220, 80, 80), t("mods.game_running"));
+            }
+        });
+    }
+
+    /// Re-read the installed mods list every [`AUTO_REFRESH_INTERVAL`] while auto-refresh is on.
+    /// Skipped while a text field has focus, since a refresh drops unsaved note and URL edits.
+    fn poll_auto_refresh(&mut self, ctx: &egui::Context) {
+        if !self.config.auto_refresh || self.win64_dir.is_empty() {
+            return;
+        }
+        if self.auto_refreshed_at.elapsed() >= AUTO_REFRESH_INTERVAL && !ctx.wants_keyboard_input() {
+            self.update_mod_list();
+            self.auto_refreshed_at = Instant::now();
+        }
+        ctx.request_repaint_after(AUTO_REFRESH_INTERVAL);
+    }
+
+    fn refresh_mod_list(&mut self) {
+        self.update_mod_list();
+        debug_println!(self, "[INFO] Refreshed mod list\n");
+    }
+
+    /// Log an error and return true if the game is running, so mod files aren't changed under it
+    fn blocked_by_running_game(&mut self) -> bool {
+        if self.game_running {
+            self.debug_output.push_str("[ERROR] The game is running. Close it before changing mods.\n");
+        }
+        self.game_running
+    }
+
+    /// Run [`core::preflight_check`] before an install, showing why it can't start if it fails
+    fn preflight_blocked(&mut self, required_bytes: u64) -> bool {
+        match core::preflight_check(&self.win64_dir, required_bytes) {
+            Ok(()) => false,
+            Err(e) => {
+                self.debug_output.push_str(&format!("[ERROR] {}\n", e));
+                self.preflight_error = Some(e.to_string());
+                true
+            }
+        }
+    }
+
+    fn reinstall_mod(&mut self, name: &str) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        match core::reinstall_mod(name, &self.win64_dir) {
+            Ok(report) if report.is_complete() => self.debug_output.push_str(&format!("[INFO] Mod '{}' reinstalled from its archive.\n", name)),
+            Ok(report) => self.debug_output.push_str(&format!("[ERROR] Mod '{}' partially reinstalled: {}\n", name, report.summary())),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to reinstall mod '{}': {}\n", name, e)),
+        }
+        self.update_mod_list();
+    }
+
+    fn uninstall_mods(&mut self, names: &[String]) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        for (name, result) in core::uninstall_mods(&self.win64_dir, names) {
+            match result {
+                Ok(0) => self.debug_output.push_str(&format!("[INFO] Mod '{}' uninstalled.\n", name)),
+                Ok(kept) => self.debug_output.push_str(&format!(
+                    "[INFO] Mod '{}' uninstalled; {} files not installed by the manager were kept in its folder.\n",
+                    name, kept
+                )),
+                Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to uninstall mod '{}': {}\n", name, e)),
+            }
+            self.checked_mods.remove(&name);
+        }
+        self.update_mod_list();
+    }
+
+    /// Restore the picked mods from a backup, one at a time so a failure doesn't stop the rest
+    fn restore_from_backup(&mut self, backup: &Path, items: &[String]) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        for item in items {
+            match core::restore_single_mod(backup, item, &self.win64_dir) {
+                Ok(count) => self.debug_output.push_str(&format!("[INFO] Restored '{}' ({} files).\n", item, count)),
+                Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to restore '{}': {}\n", item, e)),
+            }
+        }
+        self.update_mod_list();
+    }
+
+    fn select_directory(&mut self) {
+        if let Some(dir) = rfd::FileDialog::new().pick_folder() {
+            let previous_dir = std::mem::replace(&mut self.win64_dir, dir.display().to_string());
+            self.note_dir_change(previous_dir);
+            debug_println!(self, "[INFO] Selected directory: {}\n", self.win64_dir);
+            let dir = self.win64_dir.clone();
+            self.cache.remember_dir(&dir);
+            self.update_mod_list();
+        }
+    }
+
+    fn install_mod_from_file(&mut self) {
+        self.debug_output.clear();
+        self.install_choices.clear();
+        if self.blocked_by_running_game() {
+            return;
+        }
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+        } else if let Some(paths) = rfd::FileDialog::new().add_filter("Mods", &["zip", "7z", "001", "z01", "lua"]).pick_files() {
+            let (lua_paths, zip_paths): (Vec<PathBuf>, Vec<PathBuf>) =
+                paths.into_iter().partition(|path| path.extension().map(|ext| ext.eq_ignore_ascii_case("lua")).unwrap_or(false));
+            if !lua_paths.is_empty() {
+                self.install_lua_mods(&lua_paths);
+                if zip_paths.is_empty() {
+                    return;
+                }
+            }
+            // Picking several parts of a split archive installs it once
+            let mut split_sets = HashSet::new();
+            let zip_paths: Vec<String> = zip_paths
+                .iter()
+                .filter(|path| match core::find_split_parts(path) {
+                    Ok(Some(split)) => split_sets.insert(split.parts().first().cloned()),
+                    _ => true,
+                })
+                .map(|path| path.display().to_string())
+                .collect();
+            for path_str in &zip_paths {
+                debug_println!(self, "[INFO] Selected mod zip: {}\n", path_str);
+            }
+            let unclassified: Vec<String> = zip_paths.iter().filter(|path| core::is_unclassified_archive(path)).cloned().collect();
+            if self.config.default_unknown_target == UnknownModTarget::Ask && !unclassified.is_empty() {
+                // Recognized archives install right away; only the rest wait for the user's choice
+                let recognized: Vec<String> = zip_paths.into_iter().filter(|path| !unclassified.contains(path)).collect();
+                if !recognized.is_empty() {
+                    self.install_mod_zips(&recognized, None);
+                }
+                self.pending_target_choice = Some(unclassified);
+            } else {
+                self.install_mod_zips(&zip_paths, None);
+            }
+        }
+    }
+
+    /// Install single-file Lua mods, each named after its script (or its folder, for a `main.lua`)
+    fn install_lua_mods(&mut self, lua_paths: &[PathBuf]) {
+        let required = lua_paths.iter().filter_map(|path| fs::metadata(path).ok()).map(|m| m.len()).sum();
+        if self.preflight_blocked(required) {
+            return;
+        }
+        for path in lua_paths {
+            let stem = path.file_stem().and_then(|n| n.to_str()).unwrap_or_default();
+            let mod_name = if stem.eq_ignore_ascii_case("main") {
+                path.parent().and_then(|dir| dir.file_name()).and_then(|n| n.to_str()).unwrap_or(stem)
+            } else {
+                stem
+            };
+            match core::install_lua_mod(&path.display().to_string(), &self.win64_dir, mod_name) {
+                Ok(_) => self.debug_output.push_str(&format!("[INFO] Lua mod '{}' installed successfully.\n", mod_name)),
+                Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to install Lua mod '{}': {}\n", mod_name, e)),
+            }
+        }
+        self.update_mod_list();
+    }
+
+    /// Install the chosen archives, using `unknown_target` for any that can't be classified.
+    /// If any of them offer install options, the user picks those first; then if any contain
+    /// executables or scripts, or would overwrite installed files, ask before installing.
+    fn install_mod_zips(&mut self, zip_paths: &[String], unknown_target: Option<core::InstallTarget>) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        let required = zip_paths.iter().map(|path| core::archive_install_size(path)).sum();
+        if self.preflight_blocked(required) {
+            return;
+        }
+        let archives: Vec<(String, core::InstallOptions, Vec<usize>)> = zip_paths
+            .iter()
+            .filter(|path| !self.install_choices.contains_key(*path))
+            .filter_map(|path| core::read_install_options(path).map(|options| (path.clone(), options)))
+            .map(|(path, options)| {
+                let choices = options.default_choices();
+                (path, options, choices)
+            })
+            .collect();
+        if !archives.is_empty() {
+            self.pending_options = Some(PendingOptions { zip_paths: zip_paths.to_vec(), unknown_target, archives });
+            return;
+        }
+        let entries: Vec<(String, Vec<String>)> = zip_paths
+            .iter()
+            .map(|path_str| {
+                let file_name = Path::new(path_str).file_name().and_then(|n| n.to_str()).unwrap_or(path_str);
+                (file_name.to_string(), core::scan_archive_for_risky_files(path_str))
+            })
+            .filter(|(_, risky)| !risky.is_empty())
+            .collect();
+        if entries.is_empty() {
+            self.confirm_overwrites(zip_paths, unknown_target);
+        } else {
+            self.pending_risky = Some(PendingRisky { source: RiskySource::Zips(zip_paths.to_vec(), unknown_target), entries });
+        }
+    }
+
+    /// Install the archives, first asking if any of them would overwrite installed files
+    fn confirm_overwrites(&mut self, zip_paths: &[String], unknown_target: Option<core::InstallTarget>) {
+        let mut diffs = Vec::new();
+        for path_str in zip_paths {
+            // Archives that can't be read are left for the install itself to report
+            let choices = self.install_choices.get(path_str).map(Vec::as_slice);
+            if let Ok(diff) = core::diff_install(path_str, &self.win64_dir, unknown_target, choices) {
+                if diff.overwrites_existing() {
+                    let file_name = Path::new(path_str).file_name().and_then(|n| n.to_str()).unwrap_or(path_str);
+                    diffs.push((file_name.to_string(), diff));
+                }
+            }
+        }
+        if diffs.is_empty() {
+            self.run_install(zip_paths, unknown_target);
+        } else {
+            self.pending_overwrite = Some(PendingOverwrite { zip_paths: zip_paths.to_vec(), unknown_target, diffs });
+        }
+    }
+
+    fn run_install(&mut self, zip_paths: &[String], unknown_target: Option<core::InstallTarget>) {
+        let batch = core::install_mods_from_zips(zip_paths, &self.win64_dir, self.clean_install, unknown_target, &self.install_choices);
+        self.install_choices.clear();
+        for (path_str, result) in &batch.results {
+            let file_name = Path::new(path_str).file_name().and_then(|n| n.to_str()).unwrap_or("");
+            match result {
+                Ok(report) => {
+                    for sub_mod in &report.sub_mods {
+                        self.debug_output.push_str(&format!("[INFO] Installed bundled mod '{}'.\n", sub_mod));
+                    }
+                    if report.is_complete() {
+                        self.debug_output.push_str(&format!("[INFO] Mod '{}' installed successfully.\n", file_name));
+                    } else {
+                        self.debug_output.push_str(&format!("[ERROR] Mod '{}' partially installed: {}\n", file_name, report.summary()));
+                    }
+                }
+                Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to install mod '{}': {}\n", file_name, e)),
+            }
+        }
+        if batch.results.len() > 1 {
+            self.debug_output.push_str(&format!("[INFO] {}\n", batch.summary()));
+        }
+        self.update_mod_list();
+        self.cache.last_win64_dir = self.win64_dir.clone();
+        self.cache.last_installed_mods = self.installed_mods.clone();
+        self.cache.last_debug_output = self.debug_output.clone();
+        save_cache(&self.cache);
+    }
+
+    /// Handle global keyboard shortcuts and arrow-key selection in the installed mods list.
+    /// Returns true if the selection moved, so the list can scroll to it.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) -> bool {
+        let shortcut = |key| egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, key);
+        if ctx.input_mut(|i| i.consume_shortcut(&shortcut(egui::Key::O))) {
+            self.select_directory();
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&shortcut(egui::Key::I))) {
+            self.install_mod_from_file();
+        }
+        if ctx.input_mut(|i| i.consume_shortcut(&shortcut(egui::Key::U))) && self.ue4ss_task.is_none() {
+            self.queue_task(TaskKind::InstallUe4ss);
+        }
+        if ctx.input(|i| i.key_pressed(egui::Key::F5)) {
+            self.refresh_mod_list();
+        }
+        // Arrow keys belong to text fields while one is focused
+        if ctx.memory(|m| m.focused().is_some()) || self.installed_mods.is_empty() {
+            return false;
+        }
+        let (down, up) = ctx.input(|i| (i.key_pressed(egui::Key::ArrowDown), i.key_pressed(egui::Key::ArrowUp)));
+        let last = self.installed_mods.len() - 1;
+        let next = match (self.selected_mod, down, up) {
+            (None, true, _) | (None, _, true) => Some(0),
+            (Some(index), true, _) => Some((index + 1).min(last)),
+            (Some(index), _, true) => Some(index.saturating_sub(1)),
+            (current, _, _) => current,
+        };
+        let moved = next != self.selected_mod;
+        self.selected_mod = next;
+        moved
+    }
+
+    /// Mods ticked for "Uninstall Selected" and tasks waiting in the queue, which were chosen
+    /// with the current game directory in mind
+    fn pending_selection(&self) -> (usize, usize) {
+        let queued = self.tasks.iter().filter(|task| matches!(task.status, TaskStatus::Queued)).count();
+        (self.checked_mods.len(), queued)
+    }
+
+    /// Ask what to do with ticked mods and queued tasks when the game directory changes away
+    /// from `previous_dir`. While the prompt is open, further changes (e.g. typing the rest of
+    /// a path) keep the directory it was first opened for.
+    fn note_dir_change(&mut self, previous_dir: String) {
+        if self.dir_change_prompt.is_none() && previous_dir != self.win64_dir && self.pending_selection() != (0, 0) {
+            self.dir_change_prompt = Some(previous_dir);
+        }
+    }
+
+    /// Add an operation to the task queue. It starts once the tasks ahead of it have finished;
+    /// queuing one that is already waiting or running does nothing.
+    fn queue_task(&mut self, kind: TaskKind) {
+        if self.tasks.iter().any(|task| task.kind == kind && !task.is_finished()) {
+            return;
+        }
+        // Keep the output of anything still in progress
+        if self.tasks.iter().all(Task::is_finished) {
+            self.debug_output.clear();
+        }
+        self.tasks.push_back(Task { kind, status: TaskStatus::Queued });
+    }
+
+    /// Cancel whatever can be cancelled (queued tasks, downloads, and a UE4SS download that hasn't
+    /// started installing), then close the window once the running task has stopped
+    fn exit_when_finished(&mut self) {
+        for task in self.tasks.iter_mut().filter(|task| matches!(task.status, TaskStatus::Queued)) {
+            task.status = TaskStatus::Cancelled;
+        }
+        if let Some(task) = &self.ue4ss_task {
+            task.cancel.store(true, Ordering::Relaxed);
+        }
+        if let Some(task) = &self.download_task {
+            task.cancel.store(true, Ordering::Relaxed);
+        }
+        self.exit_when_idle = true;
+        self.debug_output.push_str("[INFO] Closing once the running task finishes\n");
+    }
+
+    /// Start the next queued task once nothing is running
+    fn poll_tasks(&mut self) {
+        // Hold queued tasks until the user confirms they're meant for the new game directory
+        if self.task_running() || self.dir_change_prompt.is_some() {
+            return;
+        }
+        let task = match self.tasks.iter_mut().find(|task| matches!(task.status, TaskStatus::Queued)) {
+            Some(task) => task,
+            None => return,
+        };
+        task.status = TaskStatus::Running;
+        let kind = task.kind.clone();
+        let started = match kind {
+            TaskKind::InstallUe4ss => self.start_ue4ss_install(),
+            TaskKind::UpdateAll => self.start_update_all(),
+            TaskKind::InstallFromUrl { url, allow_risky } => self.start_url_install(&url, allow_risky),
+            TaskKind::UpdateFromSource(name) => self.start_source_update(&name),
+            TaskKind::PreviewUe4ss => self.start_ue4ss_preview(),
+        };
+        if !started {
+            // Why it couldn't start is already in the debug output
+            self.finish_task(TaskStatus::Failed(t("tasks.not_started")));
+        }
+    }
+
+    /// Record how the running task ended, dropping the oldest finished tasks past [`MAX_FINISHED_TASKS`]
+    fn finish_task(&mut self, status: TaskStatus) {
+        if let Some(task) = self.tasks.iter_mut().find(|task| matches!(task.status, TaskStatus::Running)) {
+            task.status = status;
+        }
+        while self.tasks.iter().filter(|task| task.is_finished()).count() > MAX_FINISHED_TASKS {
+            if let Some(index) = self.tasks.iter().position(Task::is_finished) {
+                self.tasks.remove(index);
+            }
+        }
+    }
+
+    /// The task list: each queued, running, and recently finished operation with its status.
+    /// Queued tasks can be cancelled before they start.
+    fn tasks_ui(&mut self, ui: &mut egui::Ui) {
+        let mut cancel = None;
+        let mut cancel_download = false;
+        let mut clear_finished = false;
+        ui.horizontal(|ui| {
+            ui.heading(t("tasks.heading"));
+            if self.tasks.iter().any(Task::is_finished) && ui.small_button(t("tasks.clear_finished")).clicked() {
+                clear_finished = true;
+            }
+        });
+        for (index, task) in self.tasks.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(task.kind.label()).strong());
+                match &task.status {
+                    TaskStatus::Queued => {
+                        ui.label(egui::RichText::new(t("tasks.queued")).color(egui::Color32::GRAY));
+                        if ui.small_button(t("common.cancel")).clicked() {
+                            cancel = Some(index);
+                        }
+                    }
+                    TaskStatus::Running => {
+                        ui.spinner();
+                        ui.label(self.task_progress(&task.kind));
+                        if self.download_task.is_some() && ui.small_button(t("common.cancel")).clicked() {
+                            cancel_download = true;
+                        }
+                    }
+                    TaskStatus::Done => {
+                        ui.colored_label(egui::Color32::from_rgb(80, 170, 90), t("tasks.done"));
+                    }
+                    TaskStatus::Failed(e) => {
+                        ui.colored_label(egui::Color32::from_rgb(220, 80, 80), t("tasks.failed")).on_hover_text(e.as_str());
+                    }
+                    TaskStatus::Cancelled => {
+                        ui.label(egui::RichText::new(t("tasks.cancelled")).color(egui::Color32::GRAY));
+                    }
+                }
+            });
+        }
+        if let Some(index) = cancel {
+            self.tasks[index].status = TaskStatus::Cancelled;
+        }
+        if let Some(task) = self.download_task.as_ref().filter(|_| cancel_download) {
+            task.cancel.store(true, Ordering::Relaxed);
+        }
+        if clear_finished {
+            self.tasks.retain(|task| !task.is_finished());
+        }
+    }
+
+    /// Progress text for the running task of the given kind
+    fn task_progress(&self, kind: &TaskKind) -> String {
+        match kind {
+            TaskKind::InstallUe4ss => match self.ue4ss_task.as_ref().and_then(|task| task.scanned) {
+                Some(scanned) => t("mods.scanning_directory").replace("{count}", &scanned.to_string()),
+                None => t("mods.downloading_ue4ss"),
+            },
+            TaskKind::UpdateAll => match &self.update_task {
+                Some(task) => format!(
+                    "{}/{}, {}",
+                    task.done,
+                    task.total,
+                    t("mods.downloads_progress")
+                        .replace("{active}", &task.downloads.active.load(Ordering::Relaxed).to_string())
+                        .replace("{size}", &core::format_size(task.downloads.bytes.load(Ordering::Relaxed)))
+                ),
+                None => String::new(),
+            },
+            TaskKind::InstallFromUrl { .. } | TaskKind::UpdateFromSource(_) | TaskKind::PreviewUe4ss => match &self.download_task {
+                Some(task) => t("tasks.downloaded").replace("{size}", &core::format_size(task.downloaded.load(Ordering::Relaxed))),
+                None => String::new(),
+            },
+        }
+    }
+
+    /// Start a UE4SS install on a worker thread. Returns false if it couldn't be started.
+    fn start_ue4ss_install(&mut self) -> bool {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return false;
+        }
+        if self.blocked_by_running_game() || self.preflight_blocked(0) {
+            return false;
+        }
+        debug_println!(self, "[INFO] Installing UE4SS...\n");
+        let cancel = Arc::new(AtomicBool::new(false));
+        let worker_cancel = Arc::clone(&cancel);
+        let target_dir = self.win64_dir.clone();
+        let (tx, scan_progress) = mpsc::channel();
+        let handle = thread::spawn(move || match core::install_ue4ss(&target_dir, &worker_cancel) {
+            Ok(()) => {
+                let entries = core::list_all_files_and_dirs(&target_dir, |scanned| {
+                    let _ = tx.send(scanned);
+                });
+                Ue4ssOutcome::Installed(entries.unwrap_or_default())
+            }
+            Err(e) if core::is_cancelled(e.as_ref()) => Ue4ssOutcome::Cancelled,
+            Err(e) => Ue4ssOutcome::Failed(e.to_string()),
+        });
+        self.ue4ss_task = Some(Ue4ssTask { cancel, handle, scan_progress, scanned: None });
+        true
+    }
+
+    /// Start updating every mod on a worker thread. Returns false if it couldn't be started.
+    fn start_update_all(&mut self) -> bool {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return false;
+        }
+        if self.blocked_by_running_game() {
+            return false;
+        }
+        debug_println!(self, "[INFO] Checking {} mods for updates...\n", self.installed_mods.len());
+        let (tx, progress) = mpsc::channel();
+        let target_dir = self.win64_dir.clone();
+        let downloads = Arc::new(core::DownloadProgress::default());
+        let worker_downloads = downloads.clone();
+        let handle = thread::spawn(move || {
+            core::update_all_mods_with_progress(&target_dir, &worker_downloads, |name, result| {
+                let level = if update_failed(result) { "ERROR" } else { "INFO" };
+                let _ = tx.send(format!("[{}] Mod '{}': {}\n", level, name, result));
+            })
+        });
+        self.update_task = Some(UpdateAllTask { progress, handle, downloads, done: 0, total: self.installed_mods.len() });
+        true
+    }
+
+    /// Show per-mod progress from a running "Update All" and wrap up once it finishes
+    fn poll_update_task(&mut self) {
+        let task = match &mut self.update_task {
+            Some(task) => task,
+            None => return,
+        };
+        for line in task.progress.try_iter() {
+            task.done += 1;
+            self.debug_output.push_str(&line);
+        }
+        if !task.handle.is_finished() {
+            return;
+        }
+        let results = match self.update_task.take().map(|task| task.handle.join()) {
+            Some(Ok(results)) => results,
+            _ => {
+                self.debug_output.push_str("[ERROR] Update All stopped unexpectedly.\n");
+                self.finish_task(TaskStatus::Failed("Update All stopped unexpectedly".to_string()));
+                return;
+            }
+        };
+        let count = |is_kind: fn(&core::UpdateResult) -> bool| results.iter().filter(|(_, result)| is_kind(result)).count();
+        let updated = count(|r| matches!(r, core::UpdateResult::Updated(report) if report.is_complete()));
+        let up_to_date = count(|r| matches!(r, core::UpdateResult::UpToDate));
+        let manual = count(|r| matches!(r, core::UpdateResult::ManualUpdateRequired));
+        let failed = count(update_failed);
+        self.debug_output.push_str(&format!(
+            "[INFO] Update All finished: {} updated, {} up to date, {} need a manual update, {} failed.\n",
+            updated, up_to_date, manual, failed
+        ));
+        self.finish_task(TaskStatus::Done);
+        self.update_mod_list();
+    }
+
+    /// Collect the result of a finished background UE4SS install, if there is one
+    fn poll_ue4ss_task(&mut self) {
+        if let Some(task) = &mut self.ue4ss_task {
+            if let Some(scanned) = task.scan_progress.try_iter().last() {
+                task.scanned = Some(scanned);
+            }
+        }
+        if !matches!(&self.ue4ss_task, Some(task) if task.handle.is_finished()) {
+            return;
+        }
+        let outcome = match self.ue4ss_task.take() {
+            Some(task) => task
+                .handle
+                .join()
+                .unwrap_or_else(|_| Ue4ssOutcome::Failed("the install thread panicked".to_string())),
+            None => return,
+        };
+        match outcome {
+            Ue4ssOutcome::Installed(entries) => {
+                self.debug_output.push_str("[INFO] UE4SS installed and verified.\n");
+                self.ue4ss_settings = None;
+                self.update_mod_list();
+                self.scanned_files = entries;
+                self.finish_task(TaskStatus::Done);
+            }
+            Ue4ssOutcome::Cancelled => {
+                self.debug_output.push_str("[INFO] Download cancelled.\n");
+                self.finish_task(TaskStatus::Cancelled);
+            }
+            Ue4ssOutcome::Failed(e) => {
+                self.debug_output.push_str(&format!("[ERROR] Failed to install UE4SS: {}\n", e));
+                self.finish_task(TaskStatus::Failed(e));
+            }
+        }
+        self.cache.last_win64_dir = self.win64_dir.clone();
+        self.cache.last_scanned_files = self.scanned_files.clone();
+        self.cache.last_debug_output = self.debug_output.clone();
+        save_cache(&self.cache);
+    }
+
+    /// Queue downloading and installing a mod from `url`. Unless `allow_risky` is set, archives
+    /// with executables or scripts are held for confirmation instead.
+    fn install_from_url(&mut self, url: &str, allow_risky: bool) {
+        let url = url.trim().to_string();
+        if url.is_empty() {
+            self.debug_output.push_str("[ERROR] Enter a download URL first.\n");
+            return;
+        }
+        self.queue_task(TaskKind::InstallFromUrl { url, allow_risky });
+    }
+
+    /// Start downloading and installing a mod from `url` on a worker thread. Returns false if
+    /// it couldn't be started.
+    fn start_url_install(&mut self, url: &str, allow_risky: bool) -> bool {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return false;
+        }
+        // The size isn't known until the download finishes, so only the folder is checked here
+        if self.blocked_by_running_game() || self.preflight_blocked(0) {
+            return false;
+        }
+        debug_println!(self, "[INFO] Downloading mod from {}\n", url);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let downloaded = Arc::new(AtomicU64::new(0));
+        let (worker_cancel, worker_downloaded) = (Arc::clone(&cancel), Arc::clone(&downloaded));
+        let (url, target_dir, clean_install) = (url.to_string(), self.win64_dir.clone(), self.clean_install);
+        let kind = TaskKind::InstallFromUrl { url: url.clone(), allow_risky };
+        let handle = thread::spawn(move || {
+            DownloadOutcome::from_install(core::install_mod_from_url_with_progress(
+                &url,
+                &target_dir,
+                clean_install,
+                None,
+                allow_risky,
+                &worker_downloaded,
+                &worker_cancel,
+            ))
+        });
+        self.download_task = Some(DownloadTask { kind, cancel, downloaded, handle });
+        true
+    }
+
+    /// Collect the result of a finished background download, if there is one
+    fn poll_download_task(&mut self) {
+        if !matches!(&self.download_task, Some(task) if task.handle.is_finished()) {
+            return;
+        }
+        let (kind, outcome) = match self.download_task.take() {
+            Some(task) => (
+                task.kind,
+                task.handle.join().unwrap_or_else(|_| DownloadOutcome::Failed("the download thread panicked".to_string())),
+            ),
+            None => return,
+        };
+        let status = match kind {
+            TaskKind::InstallFromUrl { url, .. } => self.finish_url_install(&url, outcome),
+            TaskKind::UpdateFromSource(name) => self.finish_source_update(&name, outcome),
+            TaskKind::PreviewUe4ss => self.finish_ue4ss_preview(outcome),
+            _ => TaskStatus::Done,
+        };
+        self.finish_task(status);
+        self.update_mod_list();
+        self.cache.last_win64_dir = self.win64_dir.clone();
+        self.cache.last_installed_mods = self.installed_mods.clone();
+        self.cache.last_debug_output = self.debug_output.clone();
+        save_cache(&self.cache);
+    }
+
+    /// Report how installing from `url` went, returning the task's final status
+    fn finish_url_install(&mut self, url: &str, outcome: DownloadOutcome) -> TaskStatus {
+        match outcome {
+            DownloadOutcome::Installed(report) => {
+                for sub_mod in &report.sub_mods {
+                    self.debug_output.push_str(&format!("[INFO] Installed bundled mod '{}'.\n", sub_mod));
+                }
+                if report.is_complete() {
+                    self.debug_output.push_str(&format!("[INFO] Mod from {} installed successfully.\n", url));
+                    self.install_url.clear();
+                    TaskStatus::Done
+                } else {
+                    self.debug_output.push_str(&format!("[ERROR] Mod from {} partially installed: {}\n", url, report.summary()));
+                    TaskStatus::Failed(report.summary())
+                }
+            }
+            // Only a UE4SS preview ends with a file list
+            DownloadOutcome::Previewed(_) => TaskStatus::Done,
+            DownloadOutcome::Risky(message, entries) => {
+                self.debug_output.push_str(&format!("[INFO] {}\n", message));
+                self.pending_risky = Some(PendingRisky { entries: vec![(url.to_string(), entries)], source: RiskySource::Url(url.to_string()) });
+                TaskStatus::Cancelled
+            }
+            DownloadOutcome::Cancelled => {
+                self.debug_output.push_str("[INFO] Download cancelled.\n");
+                TaskStatus::Cancelled
+            }
+            DownloadOutcome::Failed(e) => {
+                self.debug_output.push_str(&format!("[ERROR] Failed to install mod from {}: {}\n", url, e));
+                TaskStatus::Failed(e)
+            }
+        }
+    }
+
+    /// Write `debug_report.txt` next to the executable and open its folder
+    fn export_debug_report(&mut self) {
+        let report = core::build_debug_report(&self.win64_dir, &self.debug_output);
+        let dir = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+            .unwrap_or_else(|| PathBuf::from("."));
+        let report_path = dir.join("debug_report.txt");
+        match fs::write(&report_path, report) {
+            Ok(()) => {
+                self.debug_output.push_str(&format!("[INFO] Debug report written to {}\n", report_path.display()));
+                open_in_file_manager(&dir);
+            }
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to write debug report: {}\n", e)),
+        }
+    }
+
+    /// Bundle recent crash logs and dumps with the mod list into a zip chosen by the user
+    fn collect_crash_report(&mut self) {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Crash report", &["zip"]).set_file_name("crash_report.zip").save_file() {
+            Some(path) => path,
+            None => return,
+        };
+        match core::write_crash_report(&self.win64_dir, &self.debug_output, &path) {
+            Ok(0) => self.debug_output.push_str(&format!(
+                "[INFO] No crash logs or dumps from the last week were found; {} only has the mod list and debug report.\n",
+                path.display()
+            )),
+            Ok(count) => {
+                self.debug_output.push_str(&format!("[INFO] Crash report with {} files written to {}\n", count, path.display()));
+                if let Some(dir) = path.parent() {
+                    open_in_file_manager(dir);
+                }
+            }
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to write crash report: {}\n", e)),
+        }
+    }
+
+    /// Fetch the configured mod index and show it in the Browse Mods window
+    fn open_mod_browser(&mut self) {
+        let (mods, fetch) = match self.config.mod_index_url.clone() {
+            Some(url) => (None, Some(thread::spawn(move || core::fetch_mod_index(&url).map_err(|e| e.to_string())))),
+            None => (Some(Err(t("browse.no_index"))), None),
+        };
+        let filter = self.mod_browser.take().map(|browser| browser.filter).unwrap_or_default();
+        self.mod_browser = Some(ModBrowser { mods, fetch, filter });
+    }
+
+    /// Show the mod index once its fetch has finished
+    fn poll_mod_browser(&mut self) {
+        let browser = match &mut self.mod_browser {
+            Some(browser) => browser,
+            None => return,
+        };
+        if !matches!(&browser.fetch, Some(fetch) if fetch.is_finished()) {
+            return;
+        }
+        if let Some(fetch) = browser.fetch.take() {
+            browser.mods = Some(fetch.join().unwrap_or_else(|_| Err("the fetch thread panicked".to_string())));
+        }
+    }
+
+    fn export_modlist(&mut self) {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Mod list", &["json"]).set_file_name("modlist.json").save_file() {
+            Some(path) => path,
+            None => return,
+        };
+        match core::build_modlist(&self.win64_dir).and_then(|modlist| core::save_modlist(&modlist, &path)) {
+            Ok(()) => self.debug_output.push_str(&format!("[INFO] Mod list exported to {}\n", path.display())),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to export mod list: {}\n", e)),
+        }
+    }
+
+    /// Save every installed mod, with its enabled state, to one zip for moving to another PC
+    fn export_all_mods(&mut self) {
+        self.debug_output.clear();
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Mods archive", &["zip"]).set_file_name("all_mods.zip").save_file() {
+            Some(path) => path,
+            None => return,
+        };
+        match core::export_all_mods(&self.win64_dir, &path) {
+            Ok(()) => self.debug_output.push_str(&format!("[INFO] All mods exported to {}\n", path.display())),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to export mods: {}\n", e)),
+        }
+    }
+
+    /// Install every mod from a zip made by Export All Mods, backing up the current ones first
+    fn import_all_mods(&mut self) {
+        self.debug_output.clear();
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        if self.blocked_by_running_game() {
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Mods archive", &["zip"]).pick_file() {
+            Some(path) => path,
+            None => return,
+        };
+        match core::import_all_mods(&path, &self.win64_dir) {
+            Ok(count) => self.debug_output.push_str(&format!("[INFO] Imported {} files from {}\n", count, path.display())),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to import mods: {}\n", e)),
+        }
+        self.update_mod_list();
+    }
+
+    /// Compare the installed mods with a mod list someone else exported
+    fn compare_modlist(&mut self) {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Mod list", &["json"]).pick_file() {
+            Some(path) => path,
+            None => return,
+        };
+        let result = core::build_modlist(&self.win64_dir)
+            .and_then(|ours| core::load_modlist(&path).map(|theirs| core::diff_modlists(&ours, &theirs)));
+        match result {
+            Ok(diff) => self.modlist_diff = Some((path.display().to_string(), diff)),
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to compare mod lists: {}\n", e)),
+        }
+    }
+
+    /// Start downloading UE4SS on a worker thread to list what it would install. Returns false
+    /// if it couldn't be started.
+    fn start_ue4ss_preview(&mut self) -> bool {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return false;
+        }
+        debug_println!(self, "[INFO] Downloading UE4SS to preview its contents...\n");
+        let cancel = Arc::new(AtomicBool::new(false));
+        let downloaded = Arc::new(AtomicU64::new(0));
+        let (worker_cancel, worker_downloaded) = (Arc::clone(&cancel), Arc::clone(&downloaded));
+        let target_dir = self.win64_dir.clone();
+        let handle = thread::spawn(move || match core::preview_ue4ss_install(&target_dir, &worker_downloaded, &worker_cancel) {
+            Ok(paths) => DownloadOutcome::Previewed(paths),
+            Err(e) if core::is_cancelled(e.as_ref()) => DownloadOutcome::Cancelled,
+            Err(e) => DownloadOutcome::Failed(e.to_string()),
+        });
+        self.download_task = Some(DownloadTask { kind: TaskKind::PreviewUe4ss, cancel, downloaded, handle });
+        true
+    }
+
+    /// Show the files a finished UE4SS preview found, returning the task's final status
+    fn finish_ue4ss_preview(&mut self, outcome: DownloadOutcome) -> TaskStatus {
+        match outcome {
+            DownloadOutcome::Previewed(paths) => {
+                let planned = paths
+                    .iter()
+                    .map(|path| {
+                        let relative = path.strip_prefix(&self.win64_dir).unwrap_or(path);
+                        (relative.display().to_string(), path.exists())
+                    })
+                    .collect();
+                self.ue4ss_preview = Some(planned);
+                TaskStatus::Done
+            }
+            DownloadOutcome::Cancelled => {
+                self.debug_output.push_str("[INFO] Download cancelled.\n");
+                TaskStatus::Cancelled
+            }
+            DownloadOutcome::Installed(_) => TaskStatus::Done,
+            DownloadOutcome::Risky(e, _) | DownloadOutcome::Failed(e) => {
+                self.debug_output.push_str(&format!("[ERROR] Failed to preview UE4SS install: {}\n", e));
+                TaskStatus::Failed(e)
+            }
+        }
+    }
+
+    fn validate_load_order(&mut self) {
+        self.debug_output.clear();
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        let issues = core::validate_load_order(&self.win64_dir);
+        self.debug_output.push_str(&format!("[INFO] Found {} problem(s) in mods.txt.\n", issues.len()));
+        self.load_order_issues = Some(issues);
+        self.confirm_load_order_fix = false;
+    }
+
+    /// Reorder `mods.txt` to match a load order someone shared
+    fn apply_load_order_template(&mut self) {
+        if self.win64_dir.is_empty() {
+            self.debug_output.push_str("[ERROR] Please select a Win64 directory first.\n");
+            return;
+        }
+        if self.blocked_by_running_game() {
+            return;
+        }
+        let path = match rfd::FileDialog::new().add_filter("Load order", &["txt", "json"]).pick_file() {
+            Some(path) => path,
+            None => return,
+        };
+        let result = core::read_load_order_template(&path).and_then(|template| core::apply_load_order_template(&self.win64_dir, &template));
+        match result {
+            Ok(not_installed) => {
+                self.debug_output.push_str(&format!("[INFO] Applied the load order from {}.\n", path.display()));
+                if !not_installed.is_empty() {
+                    self.debug_output
+                        .push_str(&format!("[INFO] Skipped {} mod(s) that aren't installed: {}\n", not_installed.len(), not_installed.join(", ")));
+                }
+            }
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to apply load order template: {}\n", e)),
+        }
+        self.update_mod_list();
+    }
+
+    fn fix_load_order(&mut self) {
+        if self.blocked_by_running_game() {
+            return;
+        }
+        match core::fix_load_order(&self.win64_dir) {
+            Ok(removed) => {
+                self.debug_output.push_str(&format!("[INFO] mods.txt fixed: removed {} line(s) and normalized formatting.\n", removed));
+                self.validate_load_order();
+            }
+            Err(e) => {
+                self.debug_output.push_str(&format!("[ERROR] Failed to fix mods.txt: {}\n", e));
+                self.confirm_load_order_fix = false;
+            }
+        }
+    }
+
+    fn reset_to_vanilla(&mut self) {
+        self.debug_output.clear();
+        if self.blocked_by_running_game() {
+            return;
+        }
+        debug_println!(self, "[INFO] Backing up and removing all mods...\n");
+        match core::remove_all_mods(&self.win64_dir, self.reset_logic_mods, self.reset_remove_ue4ss) {
+            Ok(report) => {
+                self.debug_output.push_str(&format!("[INFO] Backup saved to {}\n", report.backup_path.display()));
+                self.debug_output.push_str(&format!("[INFO] Removed {} item(s); the game is back to vanilla.\n", report.removed.len()));
+                for name in &report.removed {
+                    debug_println!(self, "[INFO] Removed {}\n", name);
+                }
+            }
+            Err(e) => self.debug_output.push_str(&format!("[ERROR] Failed to reset to vanilla: {}\n", e)),
+        }
+        self.update_mod_list();
+    }
+
+    /// Start downloading a mod from its source URL and reinstalling it on a worker thread.
+    /// Returns false if it couldn't be started.
+    fn start_source_update(&mut self, name: &str) -> bool {
+        if self.blocked_by_running_game() {
+            return false;
+        }
+        debug_println!(self, "[INFO] Updating '{}' from its source URL...\n", name);
+        let cancel = Arc::new(AtomicBool::new(false));
+        let downloaded = Arc::new(AtomicU64::new(0));
+        let (worker_cancel, worker_downloaded) = (Arc::clone(&cancel), Arc::clone(&downloaded));
+        let (mod_dir, target_dir) = (core::mods_dir(&self.win64_dir).join(name), self.win64_dir.clone());
+        let handle = thread::spawn(move || {
+            DownloadOutcome::from_install(core::redownload_mod(&mod_dir, &target_dir, &worker_downloaded, &worker_cancel))
+        });
+        self.download_task = Some(DownloadTask { kind: TaskKind::UpdateFromSource(name.to_string()), cancel, downloaded, handle });
+        true
+    }
+
+    /// Report how updating `name` from its source went, returning the task's final status
+    fn finish_source_update(&mut self, name: &str, outcome: DownloadOutcome) -> TaskStatus {
+        match outcome {
+            DownloadOutcome::Installed(report) if report.is_complete() => {
+                self.debug_output.push_str(&format!("[INFO] Mod '{}' updated from source.\n", name));
+                TaskStatus::Done
+            }
+            DownloadOutcome::Installed(report) => {
+                self.debug_output.push_str(&format!("[ERROR] Mod '{}' partially updated: {}\n", name, report.summary()));
+                TaskStatus::Failed(report.summary())
+            }
+            DownloadOutcome::Cancelled => {
+                self.debug_output.push_str("[INFO] Download cancelled.\n");
+                TaskStatus::Cancelled
+            }
+            DownloadOutcome::Previewed(_) => TaskStatus::Done,
+            DownloadOutcome::Risky(e, _) | DownloadOutcome::Failed(e) => {
+                self.debug_output.push_str(&format!("[ERROR] Failed to update '{}' from source: {}\n", name, e));
+                TaskStatus::Failed(e)
+            }
+        }
+    }
+
+    /// The installed game's version, detected once per game directory
+    fn detected_game_version(&mut self) -> Option<String> {
+        let stale = match &self.game_version {
+            Some((dir, _)) => dir != &self.win64_dir,
+            None => true,
+        };
+        if stale {
+            self.game_version = Some((self.win64_dir.clone(), core::detect_game_version(&self.win64_dir)));
+        }
+        self.game_version.as_ref().and_then(|(_, version)| version.clone())
+    }
+
+    fn update_mod_list(&mut self) {
+        self.source_urls.clear();
+        self.mod_kinds.clear();
+        self.notes.clear();
+        if self.win64_dir.is_empty() {
+            self.installed_mods.clear();
+            self.checked_mods.clear();
+            self.dependency_warnings.clear();
+            self.version_mismatches.clear();
+            self.mod_summary = None;
+            return;
+        }
+        match core::list_installed_mods_detailed(&self.win64_dir) {
+            Ok(mods) => {
+                self.managed_mods = mods.iter().filter(|m| m.managed).map(|m| m.name.clone()).collect();
+                self.favorites = mods.iter().filter(|m| m.favorite).map(|m| m.name.clone()).collect();
+                self.enabled_mods = mods.iter().filter(|m| m.enabled).map(|m| m.name.clone()).collect();
+                self.updated_at = mods.iter().filter_map(|m| m.updated.map(|updated| (m.name.clone(), updated))).collect();
+                self.mod_summary = Some(core::summarize_mods(&self.win64_dir, &mods));
+                self.compat_warnings = mods
+                    .iter()
+                    .filter_map(|m| m.compat_warning.clone().map(|warning| (m.name.clone(), warning)))
+                    .collect();
+                self.installed_mods = mods.into_iter().map(|m| m.name).collect();
+                core::sort_mods(&self.win64_dir, &mut self.installed_mods, self.config.mod_sort);
+            }
             Err(e) => {
                 self.installed_mods.clear();
+                self.managed_mods.clear();
+                self.favorites.clear();
+                self.enabled_mods.clear();
+                self.updated_at.clear();
+                self.mod_summary = None;
+                self.compat_warnings.clear();
                 self.debug_output.push_str(&format!("[ERROR] Failed to list mods: {}\n", e));
             }
         }
+        self.dependency_warnings = core::check_dependencies(&self.win64_dir);
+        self.ue4ss_installed = core::detect_ue4ss(&self.win64_dir);
+        self.version_mismatches = match self.detected_game_version() {
+            Some(version) => core::version_mismatches(&self.win64_dir, &version)
+                .into_iter()
+                .map(|mismatch| (mismatch.mod_name.clone(), mismatch))
+                .collect(),
+            None => HashMap::new(),
+        };
+        self.solo_mod = core::solo_mod_name(&self.win64_dir);
+        self.selected_mod = self.selected_mod.filter(|&index| index < self.installed_mods.len());
+        let installed = &self.installed_mods;
+        self.checked_mods.retain(|name| installed.contains(name));
         // Save cache after mod list update
         self.cache.last_installed_mods = self.installed_mods.clone();
         self.cache.last_win64_dir = self.win64_dir.clone();